@@ -0,0 +1,122 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    Run,
+    Bench,
+    Compare,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Target {
+    All,
+    Day(u8),
+}
+
+/// How `run` prints its results.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+    Markdown,
+}
+
+#[derive(Debug, Clone)]
+pub struct Args {
+    pub command: Command,
+    pub target: Target,
+    pub input: Option<std::path::PathBuf>,
+    pub save_baseline: Option<String>,
+    pub baseline: Option<String>,
+    /// Changes within this fraction of the baseline count as noise.
+    pub threshold: f64,
+    /// Per part, or per benchmarked phase, `None` for no limit.
+    pub budget: Option<std::time::Duration>,
+    /// No progress on stderr.
+    pub quiet: bool,
+    /// Number of `-v`s, for logging.
+    pub verbosity: u8,
+    pub format: Format,
+}
+
+pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Args, String> {
+    let mut args = args.into_iter();
+
+    let command = match args.next().as_deref() {
+        Some("run") => Command::Run,
+        Some("bench") => Command::Bench,
+        Some("compare") => Command::Compare,
+        Some(unknown) => return Err(format!("Unknown command '{unknown}'")),
+        None => return Err("Missing command".into()),
+    };
+
+    let target = match args.next().as_deref() {
+        Some("all") => Target::All,
+        Some(day) => Target::Day(day.parse().map_err(|_| format!("Invalid day '{day}'"))?),
+        None => return Err("Missing day".into()),
+    };
+
+    let mut parsed = Args {
+        command,
+        target,
+        input: None,
+        save_baseline: None,
+        baseline: None,
+        threshold: 0.05,
+        budget: Some(aoc_lib::runner::DEFAULT_BUDGET),
+        quiet: false,
+        verbosity: 0,
+        format: Format::Text,
+    };
+
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("Missing value for {arg}"))
+        };
+        match arg.as_str() {
+            "--save-baseline" if command == Command::Bench => parsed.save_baseline = Some(value()?),
+            "--baseline" if command == Command::Bench => parsed.baseline = Some(value()?),
+            "--threshold" if command == Command::Bench => {
+                let threshold = value()?;
+                let percent: f64 = threshold
+                    .parse()
+                    .map_err(|_| format!("Invalid threshold '{threshold}'"))?;
+                parsed.threshold = percent / 100.0;
+            }
+            "--budget" => {
+                let budget = value()?;
+                let seconds: f64 = budget
+                    .parse()
+                    .ok()
+                    .filter(|seconds: &f64| *seconds >= 0.0 && seconds.is_finite())
+                    .ok_or_else(|| format!("Invalid budget '{budget}'"))?;
+                parsed.budget =
+                    (seconds > 0.0).then(|| std::time::Duration::from_secs_f64(seconds));
+            }
+            "--quiet" | "-q" if command == Command::Run => parsed.quiet = true,
+            "--format" if command == Command::Run => {
+                parsed.format = match value()?.as_str() {
+                    "text" => Format::Text,
+                    "json" => Format::Json,
+                    "markdown" | "md" => Format::Markdown,
+                    format => return Err(format!("Unknown format '{format}'")),
+                };
+            }
+            flag if flag.starts_with('-') => {
+                if let Some(level) = aoc_lib::log::verbosity_from_flag(flag)
+                    && command == Command::Run
+                {
+                    parsed.verbosity = parsed.verbosity.saturating_add(level);
+                } else {
+                    return Err(format!("Unknown flag '{flag}'"));
+                }
+            }
+            _ if parsed.input.is_some() => return Err(format!("Unexpected argument '{arg}'")),
+            _ if target == Target::All => {
+                return Err("An input file can only be given for a single day".into());
+            }
+            input => parsed.input = Some(input.into()),
+        }
+    }
+
+    Ok(parsed)
+}
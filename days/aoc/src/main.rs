@@ -0,0 +1,251 @@
+use aoc_lib::runner::Day;
+
+mod args;
+
+const USAGE: &str = "Usage:
+    aoc run <day|all> [input] [--budget <seconds>] [--quiet] [-v|-vv|-vvv] [--format <text|json|markdown>]
+    aoc bench <day|all> [input] [--budget <seconds>] [--baseline <name>] [--save-baseline <name>] [--threshold <percent>]
+    aoc compare <day|all> [input] [--budget <seconds>]
+
+Without an input file each day reads days/aoc_<day>/input.txt. Days whose
+input cannot be read are skipped, which fails if no day is left.
+
+run gives each part a budget of 60 seconds by default, 0 for no limit. Parts
+which notice they ran out of time are reported as timed out, and parts which
+panic with their panic message. Progress of long parts is shown on stderr
+unless --quiet. -v, -vv and -vvv log increasingly detailed intermediate state
+to stderr. --format json prints every day as a
+single JSON array and --format markdown as a table, once all days are done.
+
+bench and compare give each benchmarked phase, warm-up included, the same
+budget and stop iterating once it has passed. Phases which notice they ran out
+of it are shown as timed out and left out of the statistics and the baseline.
+
+Benchmark results are also written to bench_output.txt in the workspace, or
+printed to stderr if that fails. --baseline compares against an earlier
+--save-baseline, flagging changes above the threshold (default 5%).
+
+compare runs every registered implementation of each part, failing if they
+do not agree on the answer.";
+
+const BENCH_OUTPUT: &str = "bench_output.txt";
+
+fn days() -> Vec<Day> {
+    vec![
+        Day::new::<aoc_01::Day>(),
+        Day::new::<aoc_02::Day>(),
+        Day::new::<aoc_03::Day>(),
+        Day::new::<aoc_04::Day>(),
+        Day::new::<aoc_05::Day>(),
+        Day::new::<aoc_06::Day>(),
+        Day::new::<aoc_07::Day>(),
+        Day::new::<aoc_08::Day>(),
+        Day::new::<aoc_09::Day>(),
+        Day::new::<aoc_10::Day>(),
+        Day::new::<aoc_11::Day>(),
+        Day::new::<aoc_12::Day>(),
+    ]
+}
+
+fn default_input_path(day: u8) -> std::path::PathBuf {
+    format!("days/aoc_{day:02}/input.txt").into()
+}
+
+fn usage_error(message: &str) -> ! {
+    eprintln!("{message}\n\n{USAGE}");
+    std::process::exit(2)
+}
+
+/// Reads and normalises the input of every selected day, skipping days without
+/// one. Also returns whether every input was valid UTF-8.
+fn inputs(days: Vec<Day>, args: &args::Args) -> (Vec<(Day, String)>, bool) {
+    let mut valid = true;
+    let inputs = days
+        .into_iter()
+        .filter_map(|day| {
+            let input_path = args
+                .input
+                .clone()
+                .unwrap_or_else(|| default_input_path(day.day));
+            let bytes = match std::fs::read(&input_path) {
+                Ok(bytes) => bytes,
+                Err(err) => {
+                    eprintln!(
+                        "Day {:02} skipped, could not read {}: {err}",
+                        day.day,
+                        input_path.display()
+                    );
+                    return None;
+                }
+            };
+            match aoc_lib::input::load(bytes) {
+                Ok(input) => Some((day, input)),
+                Err(err) => {
+                    parse_failed(&err.with_day(day.day));
+                    valid = false;
+                    None
+                }
+            }
+        })
+        .collect();
+    (inputs, valid)
+}
+
+/// Prints the diagnostic for input which failed to parse.
+fn parse_failed(err: &aoc_lib::ParseError) {
+    eprintln!("{}", err.diagnostic());
+}
+
+/// Returns whether every day parsed and no part panicked.
+fn run(inputs: &[(Day, String)], args: &args::Args) -> bool {
+    let config = aoc_lib::runner::Config {
+        budget: args.budget,
+    };
+    let mut parsed = true;
+    let mut reports = Vec::new();
+    for (day, input) in inputs {
+        let report = (day.run)(input, &config);
+        if let Ok(report) = &report {
+            parsed &= !report.panicked();
+        }
+        match report {
+            Ok(report) if args.format == args::Format::Text => {
+                if !reports.is_empty() {
+                    println!();
+                }
+                aoc_lib::report::print_day(&report);
+                reports.push(report);
+            }
+            Ok(report) => reports.push(report),
+            Err(err) => {
+                parse_failed(&err);
+                parsed = false;
+            }
+        }
+    }
+
+    match args.format {
+        args::Format::Text => {}
+        args::Format::Json => println!("{}", aoc_lib::report::json(&reports)),
+        args::Format::Markdown => print!("{}", aoc_lib::report::markdown(&reports)),
+    }
+    parsed
+}
+
+/// Returns whether every day parsed and the baseline, if any, was saved.
+fn bench(inputs: &[(Day, String)], args: &args::Args) -> bool {
+    use aoc_lib::bench::{self, Baseline};
+
+    let config = bench::Config {
+        budget: args.budget,
+        ..Default::default()
+    };
+    let baseline = args.baseline.as_deref().map(Baseline::load);
+
+    let mut output = String::new();
+    let mut benches = Vec::new();
+    let mut parsed = true;
+    for (day, input) in inputs {
+        let day_bench = match (day.bench)(input, &config) {
+            Ok(day_bench) => day_bench,
+            Err(err) => {
+                parse_failed(&err);
+                parsed = false;
+                continue;
+            }
+        };
+        if !benches.is_empty() {
+            output.push('\n');
+            println!();
+        }
+        let table = bench::format(&day_bench, baseline.as_ref(), args.threshold);
+        print!("{table}");
+        output.push_str(&table);
+        benches.push(day_bench);
+    }
+
+    let path = bench::workspace_root().join(BENCH_OUTPUT);
+    if let Err(err) = std::fs::write(&path, &output) {
+        eprintln!("Could not write {}: {err}\n{output}", path.display());
+    }
+
+    if let Some(name) = &args.save_baseline {
+        match Baseline::save(name, &benches) {
+            Ok(()) => println!(
+                "\nSaved baseline '{name}' to {}",
+                Baseline::path(name).display()
+            ),
+            Err(err) => {
+                eprintln!("Could not save baseline '{name}': {err}");
+                return false;
+            }
+        }
+    }
+
+    parsed
+}
+
+/// Returns whether every day parsed and every implementation agreed.
+fn compare(inputs: &[(Day, String)], args: &args::Args) -> bool {
+    let config = aoc_lib::bench::Config {
+        warm_up: std::time::Duration::from_millis(100),
+        measurement: std::time::Duration::from_millis(500),
+        budget: args.budget,
+        ..Default::default()
+    };
+
+    let mut success = true;
+    for (idx, (day, input)) in inputs.iter().enumerate() {
+        if idx != 0 {
+            println!();
+        }
+        match (day.compare)(input, &config) {
+            Ok(comparison) => {
+                print!("{}", aoc_lib::compare::format(&comparison));
+                success &= comparison.disagreements().is_empty();
+            }
+            Err(err) => {
+                parse_failed(&err);
+                success = false;
+            }
+        }
+    }
+    success
+}
+
+fn main() {
+    let args = args::parse(std::env::args().skip(1)).unwrap_or_else(|err| usage_error(&err));
+
+    let days: Vec<Day> = match args.target {
+        args::Target::All => days(),
+        args::Target::Day(day) => {
+            let days: Vec<_> = days()
+                .into_iter()
+                .filter(|entry| entry.day == day)
+                .collect();
+            if days.is_empty() {
+                usage_error(&format!("Day {day} is not solved"));
+            }
+            days
+        }
+    };
+
+    let (inputs, valid) = inputs(days, &args);
+    if inputs.is_empty() {
+        eprintln!("No input to run");
+        std::process::exit(1);
+    }
+
+    // Progress would only garble benchmark and comparison tables.
+    aoc_lib::progress::set_enabled(args.command == args::Command::Run && !args.quiet);
+    aoc_lib::log::set_verbosity(args.verbosity);
+
+    let success = match args.command {
+        args::Command::Run => run(&inputs, &args),
+        args::Command::Bench => bench(&inputs, &args),
+        args::Command::Compare => compare(&inputs, &args),
+    };
+    if !(valid && success) {
+        std::process::exit(1);
+    }
+}
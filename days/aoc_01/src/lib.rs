@@ -0,0 +1,89 @@
+use aoc_lib::ParseError;
+
+pub type Input = Vec<i64>;
+
+pub fn one(input: &Input) -> i64 {
+    let mut sum = 0;
+
+    let mut direction = 50;
+    for line in input {
+        direction += line;
+        if direction % 100 == 0 {
+            sum += 1;
+        }
+    }
+
+    sum
+}
+pub fn two(input: &Input) -> i64 {
+    let mut sum = 0;
+
+    let mut direction = 50;
+    for line in input {
+        // Capture turning through zero.
+        if direction != 0 {
+            let mod_direction = direction + (line % 100);
+            if !(0..=100).contains(&mod_direction) {
+                sum += 1;
+            }
+        }
+
+        direction += line;
+
+        // Capture turning multiple times
+        let div = (line / 100).abs();
+        sum += div;
+
+        // Capture landing on zero
+        if direction % 100 == 0 {
+            sum += 1;
+        }
+
+        // Reset dial to within [0, 99] bound
+        direction %= 100;
+        if direction < 0 {
+            direction += 100
+        }
+    }
+
+    sum
+}
+
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    input
+        .lines()
+        .map(|line| match line.chars().next() {
+            Some('R') => Ok(aoc_lib::scan!(input, line, "R{}" => i64)?.0),
+            Some('L') => Ok(-aoc_lib::scan!(input, line, "L{}" => i64)?.0),
+            _ => Err(ParseError::at(input, line, "Expected direction 'L' or 'R'")),
+        })
+        .collect()
+}
+
+pub struct Day;
+
+impl aoc_lib::Solution for Day {
+    const DAY: u8 = 1;
+
+    type Input<'a> = Input;
+
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(input: &Input) -> aoc_lib::Answer {
+        one(input).into()
+    }
+
+    fn part_two(input: &Input) -> aoc_lib::Answer {
+        two(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn examples() {
+        aoc_lib::examples::check::<crate::Day>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
@@ -0,0 +1,97 @@
+use aoc_lib::ParseError;
+use aoc_lib::automaton::{self, Neighbourhood, Neighbours};
+use aoc_lib::prefix_sum::PrefixSum2D;
+
+pub type Input = aoc_lib::Grid<bool>;
+
+pub mod points;
+
+/// A roll is accessible, and gets removed, if fewer than four of the eight
+/// surrounding positions hold rolls.
+fn keep_roll(&roll: &bool, neighbours: Neighbours<'_, bool>) -> bool {
+    roll && neighbours.filter(|&&neighbour| neighbour).count() >= 4
+}
+
+pub fn one(input: &Input) -> usize {
+    automaton::step(input, Neighbourhood::Moore(1), keep_roll).1
+}
+
+/// Counts the rolls in every 3x3 window through a summed-area table of the
+/// padded grid.
+pub fn one_prefix_sums(input: &Input) -> usize {
+    let rows: Vec<Vec<u32>> = input
+        .rows()
+        .map(|row| row.iter().map(|&roll| u32::from(roll)).collect())
+        .collect();
+    let rolls = PrefixSum2D::new(&aoc_lib::pad_input(rows, 0));
+
+    // Padding shifts every position by one, so the window around it starts at
+    // its own unpadded position.
+    input
+        .iter()
+        .filter(|&((row, col), &roll)| roll && rolls.sum((row, col), (row + 2, col + 2)) - 1 < 4)
+        .count()
+}
+
+pub fn two(input: &Input) -> usize {
+    let mut input = input.clone();
+    automaton::propagate(&mut input, Neighbourhood::Moore(1), keep_roll)
+}
+
+/// Removes every accessible roll at once, rescanning the whole grid each round.
+pub fn two_rounds(input: &Input) -> usize {
+    automaton::run_until_fixpoint(input.clone(), Neighbourhood::Moore(1), keep_roll).changed
+}
+
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    aoc_lib::Grid::try_parse(input, "'@' or '.'", |c| match c {
+        '@' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })
+}
+
+pub struct Day;
+
+impl aoc_lib::Solution for Day {
+    const DAY: u8 = 4;
+
+    type Input<'a> = Input;
+
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(input: &Input) -> aoc_lib::Answer {
+        one(input).into()
+    }
+
+    fn part_two(input: &Input) -> aoc_lib::Answer {
+        two(input).into()
+    }
+
+    // HashSets are slow, see `aoc compare 4`.
+    fn alternatives<'a>() -> Vec<aoc_lib::Implementation<Self::Input<'a>>> {
+        vec![
+            aoc_lib::Implementation::new(
+                aoc_lib::report::Part::One,
+                "points (incl. conversion)",
+                |input| points::one(&points::from_grid(input)).into(),
+            ),
+            aoc_lib::Implementation::new(aoc_lib::report::Part::One, "prefix sums", |input| {
+                one_prefix_sums(input).into()
+            }),
+            aoc_lib::Implementation::new(aoc_lib::report::Part::Two, "rounds", |input| {
+                two_rounds(input).into()
+            }),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn examples() {
+        aoc_lib::examples::check::<crate::Day>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
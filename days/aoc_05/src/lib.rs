@@ -0,0 +1,209 @@
+use aoc_lib::ParseError;
+use std::collections::VecDeque;
+
+#[derive(Debug)]
+enum Op {
+    Begin(u64),
+    End(u64),
+}
+
+impl Op {
+    pub fn id(&self) -> u64 {
+        match &self {
+            Op::Begin(id) => *id,
+            Op::End(id) => *id,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    ranges: Vec<(u64, u64)>,
+    /// Sorted while parsing, so part one doesn't need its own copy.
+    ids: Vec<u64>,
+}
+
+fn get_first_open_range(ranges: &mut VecDeque<(u64, i32)>) -> Option<(u64, u64)> {
+    let (n_begin, (begin_id, _)) = ranges
+        .iter()
+        .cloned()
+        .enumerate()
+        .find(|(_, (_, state))| state >= &1)?;
+
+    let (n_end, (end_id, _)) = ranges
+        .iter()
+        .cloned()
+        .skip(n_begin)
+        .enumerate()
+        .find(|(_, (_, state))| state == &0)?;
+
+    ranges.drain(..(n_begin + n_end));
+
+    Some((begin_id, end_id))
+}
+
+pub fn one(input: &Input) -> u64 {
+    let mut sum = 0;
+
+    let mut ranges: Vec<Op> = input
+        .ranges
+        .iter()
+        .flat_map(|&(a, b)| [Op::Begin(a), Op::End(b)])
+        .collect();
+
+    ranges.sort_unstable_by(|a, b| {
+        // If equal we always order Begin before End to prevent state counter going below zero.
+        a.id().cmp(&b.id()).then_with(|| match (a, b) {
+            (Op::Begin(_), Op::End(_)) => std::cmp::Ordering::Less,
+            (Op::End(_), Op::Begin(_)) => std::cmp::Ordering::Greater,
+            _ => std::cmp::Ordering::Equal,
+        })
+    });
+
+    let mut open_ranges: VecDeque<_> = ranges
+        .into_iter()
+        .scan(0, |state, elem| {
+            match &elem {
+                Op::Begin(_) => *state += 1,
+                Op::End(_) => *state -= 1,
+            };
+            // State should never be below zero, that means some ordering is incorrect.
+            assert!(*state >= 0, "State: {state} {elem:?}");
+            Some((elem.id(), *state))
+        })
+        .collect();
+
+    let (mut begin_id, mut end_id) = get_first_open_range(&mut open_ranges).unwrap();
+    for &id in &input.ids {
+        // If current id is past the end ID step forward to next range.
+        if id > end_id {
+            if let Some((new_begin_id, new_end_id)) = get_first_open_range(&mut open_ranges) {
+                begin_id = new_begin_id;
+                end_id = new_end_id;
+            } else {
+                break;
+            }
+        }
+        // If in range add one to sum
+        if id >= begin_id && id <= end_id {
+            sum += 1;
+        }
+    }
+
+    sum
+}
+
+pub fn two(input: &Input) -> u64 {
+    let mut sum = 0;
+
+    let mut ranges: Vec<Op> = input
+        .ranges
+        .iter()
+        .flat_map(|&(a, b)| [Op::Begin(a), Op::End(b)])
+        .collect();
+
+    ranges.sort_unstable_by(|a, b| {
+        // If equal we always order Begin before End to prevent state counter going below zero.
+        a.id().cmp(&b.id()).then_with(|| match (a, b) {
+            (Op::Begin(_), Op::End(_)) => std::cmp::Ordering::Less,
+            (Op::End(_), Op::Begin(_)) => std::cmp::Ordering::Greater,
+            _ => std::cmp::Ordering::Equal,
+        })
+    });
+
+    let mut open_ranges: VecDeque<_> = ranges
+        .into_iter()
+        .scan(0, |state, elem| {
+            match &elem {
+                Op::Begin(_) => *state += 1,
+                Op::End(_) => *state -= 1,
+            };
+            // State should never be below zero, that means some ordering is incorrect.
+            assert!(*state >= 0, "State: {state} {elem:?}");
+            Some((elem.id(), *state))
+        })
+        .collect();
+
+    while let Some((begin_id, end_id)) = get_first_open_range(&mut open_ranges) {
+        aoc_lib::debug!("merged interval {begin_id}-{end_id}");
+        // +1 since we are inclusive on both sides.
+        sum += end_id - begin_id + 1;
+    }
+
+    sum
+}
+
+/// Ranges like `3-5`, a blank line, then one id per line.
+/// Fresh ranges like `3-5` one per line, then after a blank line the ids of
+/// the available ingredients.
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    use aoc_lib::input::{blocks, lines};
+
+    let mut blocks = blocks(input);
+    let (Some(ranges), Some(ids)) = (blocks.next(), blocks.next()) else {
+        return Err(ParseError::at(
+            input,
+            &input[input.len()..],
+            "Expected a blank line followed by ids",
+        ));
+    };
+    if let Some(rest) = blocks.next() {
+        return Err(ParseError::at(input, rest, "Unexpected trailing input"));
+    }
+
+    let ranges = lines(ranges)
+        .map(|line| aoc_lib::scan!(input, line, "{}-{}" => u64, u64))
+        .collect::<Result<_, _>>()?;
+    let mut ids = aoc_lib::scan::integers(input, ids)?;
+    ids.sort_unstable();
+    Ok(Input { ranges, ids })
+}
+
+pub struct Day;
+
+impl aoc_lib::Solution for Day {
+    const DAY: u8 = 5;
+
+    type Input<'a> = Input;
+
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(input: &Input) -> aoc_lib::Answer {
+        one(input).into()
+    }
+
+    fn part_two(input: &Input) -> aoc_lib::Answer {
+        two(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn examples() {
+        aoc_lib::examples::check::<crate::Day>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn parse_errors() {
+        let tests = [
+            ("3-5\n10-14", 2, 6, "Expected a blank line followed by ids"),
+            ("3-5\n10_14\n\n1", 2, 1, "Expected '-' as in '{}-{}'"),
+            (
+                "3-5\n\n1\n99999999999999999999",
+                4,
+                1,
+                "number too large to fit in target type: '99999999999999999999'",
+            ),
+            ("3-5\n\n1\n\n2", 5, 1, "Unexpected trailing input"),
+        ];
+
+        for (input, line, column, message) in tests {
+            let err = crate::parse(input).unwrap_err();
+            assert_eq!((err.line, err.column), (line, column), "{input:?}");
+            assert_eq!(err.message, message, "{input:?}");
+        }
+    }
+}
@@ -0,0 +1,281 @@
+use aoc_lib::ParseError;
+
+#[derive(Debug, Clone, Copy)]
+enum Op {
+    Num(i64),
+    Addition,
+    Multiplication,
+}
+
+impl Op {
+    fn num_or_panic(&self) -> &i64 {
+        match self {
+            Op::Num(num) => num,
+            _ => panic!("'{self}' is not num"),
+        }
+    }
+}
+
+impl std::fmt::Display for Op {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let output = match self {
+            Op::Num(num) => num.to_string(),
+            Op::Addition => "+".into(),
+            Op::Multiplication => "*".into(),
+        };
+        write!(f, "{output}")
+    }
+}
+
+type InputOne = Vec<Vec<Op>>;
+
+pub struct Input<'a> {
+    /// The rows as they are, part two reads the digits column by column.
+    rows: Vec<&'a str>,
+    /// Numbers of every row, with the operands as the last row.
+    ops: InputOne,
+}
+
+pub fn one(input: &Input<'_>) -> i64 {
+    let input = aoc_lib::transpose2(input.ops.clone());
+
+    let sum: i64 = input
+        .into_iter()
+        .map(|mut ops| {
+            // Rotate CCW makes last element = operand
+            match ops.pop().unwrap() {
+                Op::Addition => ops.iter().map(|op| op.num_or_panic()).sum::<i64>(),
+                Op::Multiplication => ops.iter().map(|op| op.num_or_panic()).product::<i64>(),
+                op => panic!("Last element '{op}' is not operand"),
+            }
+        })
+        .sum();
+
+    sum
+}
+
+// Nasty but works. With this method I align each number with the operand. Leaidng to really
+// nasty substring parsing and cloning of data.
+//
+// A much smarter method would be to pop off numbers by the end of the input and then whenver encountering
+// an operand do the summing.
+pub fn two(input: &Input<'_>) -> u64 {
+    let mut input = parse_two(input.rows.iter().map(|row| row.to_string()).collect());
+
+    let mut sum = 0;
+    for (op, row) in input.iter_mut() {
+        let mut numbers = Vec::new();
+        while let Some(num) = row
+            .iter_mut()
+            .map(|elem| elem.pop())
+            .collect::<Option<String>>()
+        {
+            if let Ok(number) = num.trim().parse::<u64>() {
+                numbers.push(number)
+            }
+        }
+        sum += match op {
+            Op::Addition => numbers.into_iter().sum::<u64>(),
+            Op::Multiplication => numbers.into_iter().product::<u64>(),
+            Op::Num(_) => unreachable!(),
+        };
+    }
+
+    sum
+}
+
+// Nasty but works. Iterate over windows and align it using the operands while
+// using the next number to know how long they are, and then include "#" as just
+// junk data to signify the last window with the length to know how long the last
+// chunk of data is.
+//
+// Yes. Awful.
+fn parse_two(mut input: Vec<String>) -> Vec<(Op, Vec<String>)> {
+    pad_rows(&mut input);
+    let operands = input.pop().unwrap();
+    let mut number_starts: Vec<_> = operands
+        .char_indices()
+        .filter(|(_, elem)| elem != &' ')
+        .collect();
+    // Manually add the ending boundary usng the length.
+    number_starts.push((operands.len(), '#'));
+
+    let mut nums = Vec::new();
+
+    for window in number_starts[..number_starts.len()].windows(2) {
+        let mut col = Vec::new();
+
+        let num = window[0];
+        let next = window[1];
+        let size = next.0 - num.0;
+        for row in input.iter_mut() {
+            let rest_of_string = row.split_off(size);
+            col.push(row.clone());
+            *row = rest_of_string
+        }
+
+        nums.push((
+            match num.1 {
+                '*' => Op::Multiplication,
+                '+' => Op::Addition,
+                _ => unreachable!(),
+            },
+            col,
+        ));
+    }
+
+    nums
+}
+
+// Editors like to strip trailing whitespace, pad the rows back to the same
+// width to keep the columns aligned.
+fn pad_rows(input: &mut [String]) {
+    let width = input.iter().map(String::len).max().unwrap_or(0);
+    for row in input.iter_mut() {
+        row.extend(std::iter::repeat_n(' ', width - row.len()));
+    }
+}
+
+pub fn two_clean(input: &Input<'_>) -> u64 {
+    // Missing trailing columns read as spaces, instead of padding a copy of
+    // the input.
+    let width = input.rows.iter().map(|row| row.len()).max().unwrap_or(0);
+    let mut rows: Vec<_> = input
+        .rows
+        .iter()
+        .map(|row| {
+            row.chars()
+                .chain(std::iter::repeat_n(' ', width - row.len()))
+                .rev()
+        })
+        .collect();
+
+    let mut sum: u64 = 0;
+    let mut buffer: Vec<u64> = Vec::with_capacity(5);
+
+    while let Some(column) = rows
+        .iter_mut()
+        .map(|row| row.next())
+        .collect::<Option<Vec<_>>>()
+    {
+        // Grab last row for operand and the rest as the nuber, nothing to do
+        // without any rows.
+        let Some((operand, num)) = column.split_last() else {
+            break;
+        };
+
+        // To make this more efficient we could build the number ourself directly
+        // from the char parts and its location instead of collecting a string.
+        // But this is much cleaner code.
+        if let Ok(num) = num.iter().collect::<String>().trim().parse::<u64>() {
+            buffer.push(num);
+        }
+        match operand {
+            '+' => {
+                sum += buffer.iter().sum::<u64>();
+                buffer.clear();
+            }
+            '*' => {
+                sum += buffer.iter().product::<u64>();
+                buffer.clear();
+            }
+            ' ' => (),
+            op => unreachable!("unknown operand {op}"),
+        }
+    }
+    sum
+}
+
+/// Rows of numbers above a row of operands, one problem per column.
+pub fn parse(input: &str) -> Result<Input<'_>, ParseError> {
+    let rows: Vec<&str> = input.lines().collect();
+    let Some((operands, numbers)) = rows.split_last() else {
+        return Err(ParseError::at(input, input, "Expected a row of operands"));
+    };
+
+    let operands = operands
+        .split_ascii_whitespace()
+        .map(|operand| match operand {
+            "+" => Ok(Op::Addition),
+            "*" => Ok(Op::Multiplication),
+            _ => Err(ParseError::at(
+                input,
+                operand,
+                "Expected operand '+' or '*'",
+            )),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut ops = numbers
+        .iter()
+        .map(|row| {
+            let nums = row
+                .split_ascii_whitespace()
+                .map(|num| aoc_lib::parse_span(input, num).map(Op::Num))
+                .collect::<Result<Vec<_>, _>>()?;
+            if nums.len() != operands.len() {
+                return Err(ParseError::at(
+                    input,
+                    row,
+                    format!("Expected {} numbers, got {}", operands.len(), nums.len()),
+                ));
+            }
+            Ok(nums)
+        })
+        .collect::<Result<InputOne, _>>()?;
+    ops.push(operands);
+
+    Ok(Input { rows, ops })
+}
+
+pub struct Day;
+
+impl aoc_lib::Solution for Day {
+    const DAY: u8 = 6;
+
+    type Input<'a> = Input<'a>;
+
+    fn parse(input: &str) -> Result<Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(input: &Input<'_>) -> aoc_lib::Answer {
+        one(input).into()
+    }
+
+    fn part_two(input: &Input<'_>) -> aoc_lib::Answer {
+        two_clean(input).into()
+    }
+
+    fn alternatives<'a>() -> Vec<aoc_lib::Implementation<Self::Input<'a>>> {
+        vec![aoc_lib::Implementation::new(
+            aoc_lib::report::Part::Two,
+            "aligned columns",
+            |input| two(input).into(),
+        )]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn examples() {
+        aoc_lib::examples::check::<crate::Day>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn parse_errors() {
+        let tests = [
+            ("", 1, 1, "Expected a row of operands"),
+            ("1 2\n3 x\n* +", 2, 3, "invalid digit found in string: 'x'"),
+            ("1 2\n3\n* +", 2, 1, "Expected 2 numbers, got 1"),
+            ("1 2\n3 4\n* -", 3, 3, "Expected operand '+' or '*'"),
+        ];
+
+        for (input, line, column, message) in tests {
+            let err = super::parse(input).err().unwrap();
+            assert_eq!((err.line, err.column), (line, column), "{input:?}");
+            assert_eq!(err.message, message, "{input:?}");
+        }
+    }
+}
@@ -0,0 +1,186 @@
+use std::vec;
+
+use aoc_lib::ParseError;
+
+pub type Input<'a> = Vec<&'a str>;
+
+// Count number of splits if it has a splitter
+// above it and -1 or +1 in columns.
+pub fn one(input: &Input<'_>) -> u64 {
+    let mut sum = 0;
+
+    let source = u64::try_from(
+        input[0]
+            .char_indices()
+            .find(|(_, elem)| elem == &'S')
+            .unwrap()
+            .0,
+    )
+    .unwrap();
+
+    let mut beam_cols: std::collections::HashSet<_> = vec![source].into_iter().collect();
+    let mut new_cols = std::collections::HashSet::new();
+
+    let mut input: Vec<_> = input
+        .iter()
+        .filter(|row| !row.chars().into_iter().all(|char: char| char == '.'))
+        .skip(1)
+        .filter_map(|row| {
+            let row = row
+                .char_indices()
+                .filter_map(|(col, elem)| match elem {
+                    '^' => Some(u64::try_from(col).unwrap()),
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+            if row.is_empty() { None } else { Some(row) }
+        })
+        .collect();
+
+    // We want to iterate top down
+    input.reverse();
+    while let Some(row) = input.pop() {
+        for splitter in row {
+            if beam_cols.contains(&splitter) {
+                // Insert the new split beamss
+                new_cols.insert(splitter - 1);
+                new_cols.insert(splitter + 1);
+
+                // Remove the terminated beam
+                beam_cols.remove(&splitter);
+                sum += 1;
+            }
+        }
+
+        // Extend the beams which did not meet a splitter.
+        new_cols.extend(beam_cols.drain());
+
+        // Swap memory around.
+        std::mem::swap(&mut beam_cols, &mut new_cols)
+    }
+
+    sum
+}
+
+// Just count the
+pub fn two(input: &Input<'_>) -> u64 {
+    let source = input[0].find('S').unwrap();
+    let mut timelines = vec![1_u64; input[0].len()];
+
+    let mut splitters: Vec<_> = input
+        .iter()
+        .filter(|row| !row.chars().into_iter().all(|char: char| char == '.'))
+        .skip(1)
+        .filter_map(|row| {
+            let col_idxs = row
+                .char_indices()
+                .filter_map(|(col_idx, elem)| match elem {
+                    '^' => Some(col_idx),
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+            if col_idxs.is_empty() {
+                None
+            } else {
+                Some(col_idxs)
+            }
+        })
+        .collect();
+
+    // Go from bottom up. Everytime we encountner a splitter keep adding the timelienes.
+    splitters.reverse();
+    for line in splitters {
+        for splitter in line {
+            timelines[splitter] = timelines[splitter - 1] + timelines[splitter + 1];
+        }
+    }
+
+    timelines[source]
+}
+
+/// Rows of the same width, with the beam entering at `S` in the first row.
+pub fn parse(input: &str) -> Result<Input<'_>, ParseError> {
+    let rows: Input = input.lines().collect();
+    let Some(first) = rows.first() else {
+        return Err(ParseError::at(input, input, "Expected the manifold"));
+    };
+    if !first.contains('S') {
+        return Err(ParseError::at(input, first, "Expected the source 'S'"));
+    }
+
+    let width = first.len();
+    for (idx, row) in rows.iter().enumerate() {
+        if row.len() != width {
+            return Err(ParseError::at(
+                input,
+                row,
+                format!("Expected a row {width} wide, got {}", row.len()),
+            ));
+        }
+        for (col, elem) in row.char_indices() {
+            let span = &row[col..col + elem.len_utf8()];
+            match elem {
+                '.' => (),
+                'S' if idx == 0 => (),
+                // The beam splits into both neighbouring columns.
+                '^' if col == 0 || col + 1 == width => {
+                    return Err(ParseError::at(input, span, "Splitter at the edge"));
+                }
+                '^' if idx != 0 => (),
+                _ if idx == 0 => {
+                    return Err(ParseError::at(input, span, "Expected '.' or 'S'"));
+                }
+                _ => return Err(ParseError::at(input, span, "Expected '.' or '^'")),
+            }
+        }
+    }
+    Ok(rows)
+}
+
+pub struct Day;
+
+impl aoc_lib::Solution for Day {
+    const DAY: u8 = 7;
+
+    type Input<'a> = Input<'a>;
+
+    fn parse(input: &str) -> Result<Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(input: &Input<'_>) -> aoc_lib::Answer {
+        one(input).into()
+    }
+
+    fn part_two(input: &Input<'_>) -> aoc_lib::Answer {
+        two(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn examples() {
+        aoc_lib::examples::check::<crate::Day>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn parse_errors() {
+        let tests = [
+            ("", 1, 1, "Expected the manifold"),
+            ("...\n.^.", 1, 1, "Expected the source 'S'"),
+            (".S.\n.^", 2, 1, "Expected a row 3 wide, got 2"),
+            (".S.\n^..", 2, 1, "Splitter at the edge"),
+            (".S^\n...", 1, 3, "Splitter at the edge"),
+            (".S.\n.S.", 2, 2, "Expected '.' or '^'"),
+            ("^S..\n....", 1, 1, "Splitter at the edge"),
+            (".S#.\n....", 1, 3, "Expected '.' or 'S'"),
+        ];
+
+        for (input, line, column, message) in tests {
+            let err = crate::parse(input).err().unwrap();
+            assert_eq!((err.line, err.column), (line, column), "{input:?}");
+            assert_eq!(err.message, message, "{input:?}");
+        }
+    }
+}
@@ -0,0 +1,114 @@
+use aoc_lib::{
+    geometry::{self, Index},
+    point::Point3,
+    span,
+};
+
+/// Junction box positions.
+pub type Input = Vec<Point3<i64>>;
+
+pub fn one(points: &Input, index: Index) -> u64 {
+    let connections = if points.len() > 100 { 1000 } else { 10 };
+
+    let edges = span::time("closest pairs", || {
+        geometry::closest_pairs(points, connections, index)
+    });
+
+    let _circuits = span::enter("circuits");
+    // Every junction box starts out as its own circuit.
+    let mut circuits = aoc_lib::UnionFind::new(points.len());
+    for edge in edges {
+        circuits.union(edge.a, edge.b);
+    }
+
+    let mut sizes: Vec<_> = circuits.component_sizes().collect();
+    // Reversed to have largest value first.
+    sizes.sort_unstable_by(|a, b| b.cmp(a));
+    aoc_lib::info!("{} circuits after {connections} connections", sizes.len());
+    aoc_lib::debug!("circuit sizes: {sizes:?}");
+
+    // Fewer than three circuits only happen for tiny inputs, take what there is.
+    sizes.iter().take(3).map(|&size| size as u64).product()
+}
+
+// Connecting the closest pairs until everything is one circuit is Kruskal's
+// algorithm, so the last connection is the longest edge of the minimum spanning
+// tree.
+pub fn two(points: &Input, index: Index) -> i64 {
+    let last = span::time("spanning tree", || {
+        geometry::minimum_spanning_tree(points, index)
+    })
+    .pop()
+    .expect("At least two junction boxes");
+    aoc_lib::debug!("last connection {} - {}", points[last.a], points[last.b]);
+
+    points[last.a].x * points[last.b].x
+}
+
+/// One junction box per line, like `162,817,812`. At least two, so there is
+/// something to connect.
+pub fn parse(input: &str) -> Result<Input, aoc_lib::ParseError> {
+    let points = input
+        .lines()
+        .map(|line| aoc_lib::parse_span(input, line))
+        .collect::<Result<Input, _>>()?;
+    if points.len() < 2 {
+        return Err(aoc_lib::ParseError::at(
+            input,
+            &input[input.len()..],
+            "Expected at least two junction boxes",
+        ));
+    }
+    Ok(points)
+}
+
+pub struct Day;
+
+impl aoc_lib::Solution for Day {
+    const DAY: u8 = 8;
+
+    type Input<'a> = Input;
+
+    fn parse(input: &str) -> Result<Input, aoc_lib::ParseError> {
+        parse(input)
+    }
+
+    fn part_one(input: &Input) -> aoc_lib::Answer {
+        one(input, Index::KdTree).into()
+    }
+
+    fn part_two(input: &Input) -> aoc_lib::Answer {
+        two(input, Index::KdTree).into()
+    }
+
+    fn alternatives<'a>() -> Vec<aoc_lib::Implementation<Self::Input<'a>>> {
+        vec![
+            aoc_lib::Implementation::new(aoc_lib::report::Part::One, "brute force", |input| {
+                one(input, Index::BruteForce).into()
+            }),
+            aoc_lib::Implementation::new(aoc_lib::report::Part::Two, "brute force", |input| {
+                two(input, Index::BruteForce).into()
+            }),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn examples() {
+        aoc_lib::examples::check::<crate::Day>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn tiny_inputs() {
+        let err = crate::parse("1,2,3").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 6));
+        assert_eq!(err.message, "Expected at least two junction boxes");
+        assert!(crate::parse("").is_err());
+
+        let points = crate::parse("0,0,0\n5,0,0").unwrap();
+        assert_eq!(crate::one(&points, crate::Index::KdTree), 2);
+        assert_eq!(crate::two(&points, crate::Index::KdTree), 0);
+    }
+}
@@ -0,0 +1,169 @@
+use aoc_lib::{ParseError, compress::Compressor, point::Point2, polygon, prefix_sum::PrefixSum2D};
+
+type Point = Point2<i64>;
+
+/// Red tiles, x being the column and y the row.
+pub type Input = Vec<Point2<i64>>;
+
+/// Area of the rectangle with opposite corners on the two tiles.
+fn area(a: Point, b: Point) -> i64 {
+    // +1 since we are creating an inclusive area.
+    let diff = b - a;
+    (diff.x.abs() + 1) * (diff.y.abs() + 1)
+}
+
+pub fn one(red_tiles: &Input) -> i64 {
+    let mut max_area: i64 = 0;
+
+    for (i, tile_1) in red_tiles.iter().enumerate() {
+        for tile_2 in red_tiles[i + 1..].iter() {
+            let area = area(*tile_1, *tile_2);
+            if area > max_area {
+                max_area = area
+            }
+        }
+    }
+
+    max_area
+}
+
+/// Marks the compressed cells outside the polygon, by drawing the boundary and
+/// flood filling from the corner. With gap markers every cell is either entirely
+/// inside or entirely outside, and the corner is padding outside the polygon.
+fn outside(polygon: &[Point], xs: &Compressor<i64>, ys: &Compressor<i64>) -> aoc_lib::Grid<bool> {
+    let mut boundary = aoc_lib::Grid::filled(xs.len(), ys.len(), false);
+    for (a, b) in polygon::edges(polygon) {
+        // Checked by `parse`.
+        debug_assert!(a.x == b.x || a.y == b.y, "Edges are horizontal or vertical");
+        let (col_a, col_b) = (xs.index(a.x).unwrap(), xs.index(b.x).unwrap());
+        let (row_a, row_b) = (ys.index(a.y).unwrap(), ys.index(b.y).unwrap());
+        for row in row_a.min(row_b)..=row_a.max(row_b) {
+            for col in col_a.min(col_b)..=col_a.max(col_b) {
+                boundary[(row, col)] = true;
+            }
+        }
+    }
+
+    let mut outside = aoc_lib::Grid::filled(xs.len(), ys.len(), false);
+    let mut stack = vec![(0, 0)];
+    outside[(0, 0)] = true;
+    while let Some(pos) = stack.pop() {
+        for neighbour in boundary.neighbours4(pos) {
+            if !boundary[neighbour] && !outside[neighbour] {
+                outside[neighbour] = true;
+                stack.push(neighbour);
+            }
+        }
+    }
+    outside
+}
+
+/// Coordinate compresses the tiles and rasterises the polygon, then checks each
+/// rectangle in O(1) by counting the outside cells it covers through 2D prefix
+/// sums, so O(n²) overall.
+pub fn two(polygon: &Input) -> i64 {
+    let xs = Compressor::with_gaps(polygon.iter().map(|tile| tile.x));
+    let ys = Compressor::with_gaps(polygon.iter().map(|tile| tile.y));
+    let outside = outside(polygon, &xs, &ys);
+
+    let outside = PrefixSum2D::from_fn(ys.len(), xs.len(), |pos| u32::from(outside[pos]));
+
+    let cells: Vec<_> = polygon
+        .iter()
+        .map(|tile| (ys.index(tile.y).unwrap(), xs.index(tile.x).unwrap()))
+        .collect();
+
+    let mut max_area = 0;
+    for (i, (tile_1, &cell_1)) in polygon.iter().zip(&cells).enumerate() {
+        for (tile_2, &cell_2) in polygon[i + 1..].iter().zip(&cells[i + 1..]) {
+            let area = area(*tile_1, *tile_2);
+            if area <= max_area {
+                continue;
+            }
+
+            if outside.sum_between(cell_1, cell_2) == 0 {
+                max_area = area;
+            }
+        }
+    }
+
+    max_area
+}
+
+/// One red tile per line, like `7,1`. The tiles are the corners of a loop, so
+/// each shares a row or column with the next, wrapping around.
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    let lines: Vec<_> = input.lines().collect();
+    let red_tiles = lines
+        .iter()
+        .map(|line| aoc_lib::parse_span(input, line))
+        .collect::<Result<Input, _>>()?;
+    if red_tiles.is_empty() {
+        return Err(ParseError::at(input, input, "Expected a red tile"));
+    }
+
+    for (idx, (a, b)) in polygon::edges(&red_tiles).enumerate() {
+        if a.x != b.x && a.y != b.y {
+            return Err(ParseError::at(
+                input,
+                lines[(idx + 1) % lines.len()],
+                format!("Expected a tile in the same row or column as {a}"),
+            ));
+        }
+    }
+    Ok(red_tiles)
+}
+
+pub struct Day;
+
+impl aoc_lib::Solution for Day {
+    const DAY: u8 = 9;
+
+    type Input<'a> = Input;
+
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(input: &Input) -> aoc_lib::Answer {
+        one(input).into()
+    }
+
+    fn part_two(input: &Input) -> aoc_lib::Answer {
+        two(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn examples() {
+        aoc_lib::examples::check::<crate::Day>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn parse_errors() {
+        let tests = [
+            ("", 1, 1, "Expected a red tile"),
+            (
+                "1,1\n5,1\n5,4\n2,3",
+                4,
+                1,
+                "Expected a tile in the same row or column as 5,4",
+            ),
+            // The loop closes from the last tile back to the first.
+            (
+                "1,1\n5,1\n5,4\n2,4",
+                1,
+                1,
+                "Expected a tile in the same row or column as 2,4",
+            ),
+        ];
+
+        for (input, line, column, message) in tests {
+            let err = crate::parse(input).unwrap_err();
+            assert_eq!((err.line, err.column), (line, column), "{input:?}");
+            assert_eq!(err.message, message, "{input:?}");
+        }
+    }
+}
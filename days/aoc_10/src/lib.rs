@@ -0,0 +1,174 @@
+use aoc_lib::ParseError;
+
+pub type Input = Vec<Machine>;
+
+/// Target lights, buttons and target joltage.
+pub type Machine = (Vec<bool>, Vec<Vec<usize>>, Vec<usize>);
+
+// BFS. Can't be a DFS since then we can take too many bad steps and stumble upon a state we could
+// reach easier starting from a different button.
+pub fn one(input: &Input) -> usize {
+    let mut sum = 0;
+
+    let mut queue = std::collections::VecDeque::new();
+    let mut visited = std::collections::HashSet::new();
+
+    for (target_lights, buttons, _) in input {
+        let state = vec![false; target_lights.len()];
+        let mut fewest_steps = usize::MAX;
+
+        // BFS where we terminate early if we've found a quicker path.
+        for button in buttons {
+            queue.push_back((button, state.clone(), 0));
+        }
+
+        // BFS = Pop_front
+        while let Some((button, mut state, mut steps)) = queue.pop_front() {
+            if steps >= fewest_steps {
+                continue;
+            }
+
+            // Apply change by switching buttons.
+            for change in button {
+                state[*change] = !state[*change]
+            }
+
+            steps += 1;
+            // If we've reached the target, stop searching.
+            if state == *target_lights {
+                fewest_steps = steps;
+                continue;
+            }
+
+            // Add all buttons again
+            if visited.insert(state.clone()) {
+                for button in buttons {
+                    queue.push_back((button, state.clone(), steps));
+                }
+            }
+        }
+        sum += fewest_steps;
+
+        queue.clear();
+        visited.clear();
+    }
+
+    sum
+}
+
+// Now the search space is even larger.... Now we need to be smart. A regular BFS doesn't even terminate for the first problem.
+pub fn two(input: &Input) -> usize {
+    let mut sum = 0;
+
+    let mut queue = std::collections::VecDeque::new();
+    let mut visited = std::collections::HashSet::new();
+    // Doesn't finish on real input, so give up once out of time.
+    let token = aoc_lib::budget::token();
+    let mut progress = aoc_lib::progress::Reporter::new("machines", input.len());
+    for (_, buttons, target_joltage) in input {
+        let state = vec![0_usize; target_joltage.len()];
+        let mut fewest_steps = usize::MAX;
+
+        // BFS where we terminate early if we've found a quicker path.
+        for button in buttons {
+            queue.push_back((button, state.clone(), 0));
+        }
+
+        // BFS = Pop_front
+        while let Some((button, mut state, mut steps)) = queue.pop_front() {
+            if steps >= fewest_steps {
+                continue;
+            }
+
+            if token.is_cancelled() {
+                return sum;
+            }
+
+            // If any button value is above the target then kill the branch.
+            if state.iter().zip(target_joltage.iter()).any(|(a, b)| a > b) {
+                continue;
+            }
+
+            // Apply change by switching buttons.
+            for change in button {
+                state[*change] += 1
+            }
+
+            steps += 1;
+            // If we've reached the target, stop searching.
+            if state == *target_joltage {
+                fewest_steps = steps;
+                continue;
+            }
+
+            // Add all buttons again
+            if visited.insert(state.clone()) {
+                for button in buttons {
+                    queue.push_back((button, state.clone(), steps));
+                }
+            }
+        }
+        sum += fewest_steps;
+
+        queue.clear();
+        visited.clear();
+        progress.inc();
+    }
+
+    sum
+}
+
+/// One machine per line, like `[.##.] (3) (1,3) (2) {3,5,4,7}`.
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    use aoc_lib::parser::{cell, delimited, literal, many, map, separated_by, seq, unsigned};
+
+    let light = cell("a light, '#' or '.'", |light| match light {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    });
+    let numbers = || separated_by(unsigned(), literal(","));
+
+    let lights = delimited(literal("["), many(light), literal("]"));
+    let buttons = separated_by(
+        delimited(literal("("), numbers(), literal(")")),
+        literal(" "),
+    );
+    let joltage = delimited(literal("{"), numbers(), literal("}"));
+
+    let machine = seq((lights, literal(" "), buttons, literal(" "), joltage));
+    aoc_lib::parser::parse_lines(
+        input,
+        map(machine, |(lights, _, buttons, _, joltage)| {
+            (lights, buttons, joltage)
+        }),
+    )
+}
+
+pub struct Day;
+
+impl aoc_lib::Solution for Day {
+    const DAY: u8 = 10;
+
+    type Input<'a> = Input;
+
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        parse(input)
+    }
+
+    fn part_one(input: &Input) -> aoc_lib::Answer {
+        one(input).into()
+    }
+
+    fn part_two(input: &Input) -> aoc_lib::Answer {
+        two(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn examples() {
+        aoc_lib::examples::check::<crate::Day>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
@@ -0,0 +1,187 @@
+/// Every device with the devices its outputs lead to.
+pub type Input<'a> = std::collections::HashMap<&'a str, Vec<&'a str>>;
+
+const END: &str = "out";
+const MUST_VISIT: [&str; 2] = ["dac", "fft"];
+
+// Extremely simple brute force DFS. For example no smartness keeping track of
+// which paths we've taken to reduce the search space.
+fn dfs_part_one<'a>(
+    adjacency_list: &std::collections::HashMap<&'a str, Vec<&'a str>>,
+    queue: &mut Vec<&'a str>,
+) -> u64 {
+    let edges = adjacency_list.get(queue.pop().unwrap()).unwrap();
+    let mut sum = 0;
+    for next_vertice in edges {
+        // If we found an end using this path, return 1.
+        if *next_vertice == END {
+            return 1;
+        } else {
+            // Else sum up all ends found by taking the next step.
+            queue.push(*next_vertice);
+            sum += dfs_part_one(adjacency_list, queue);
+        }
+    }
+    sum
+}
+
+// Idea: DFS with a list of visited nodes.
+// `None` without the start, e.g. in the example for the other part.
+pub fn one(adjacency_list: &Input<'_>) -> Option<u64> {
+    adjacency_list
+        .contains_key("you")
+        .then(|| dfs_part_one(adjacency_list, &mut vec!["you"]))
+}
+
+// I bet there's no cycles and that we need to manage a magnitudes larger search space.
+//
+// 1. Brute force for like a minute = no cycle so shouldnt exist any.
+//
+// Time to add memoization....
+fn dfs_part_two<'a>(
+    adjacency_list: &std::collections::HashMap<&'a str, Vec<&'a str>>,
+    queue: &mut Vec<&'a str>,
+    // Need to sort the memoization key.
+    visited_required: Vec<&'a str>,
+    memoization: &mut std::collections::HashMap<(&'a str, Vec<&'a str>), u64>,
+) -> u64 {
+    let current = queue.pop().unwrap();
+
+    // Check if we've already seen this grouping of current vertice and visited requirements.
+    if let Some(&cached) = memoization.get(&(current, visited_required.clone())) {
+        return cached;
+    }
+
+    let edges = adjacency_list.get(current).unwrap();
+    let mut sum = 0;
+
+    for next_vertice in edges {
+        if *next_vertice == END {
+            if visited_required.len() == MUST_VISIT.len() {
+                sum += 1
+            }
+        } else {
+            // Else sum up all ends found by taking the next step.
+            queue.push(*next_vertice);
+
+            let mut visited_required: Vec<&str> = visited_required.clone();
+
+            if MUST_VISIT.contains(next_vertice) && !visited_required.contains(next_vertice) {
+                visited_required.push(next_vertice);
+                // Since we're using a Vec we need to ensure that the order is always the
+                // same. A HashSet or btreeset would be neater but contain unnecessary overhead
+                // for a Vec of size 2.
+                visited_required.sort_unstable();
+            }
+
+            // Copy the current path every time we split into the outbound edges.
+            sum += dfs_part_two(adjacency_list, queue, visited_required, memoization);
+        }
+    }
+    memoization.insert((current, visited_required), sum);
+    sum
+}
+
+pub fn two(adjacency_list: &Input<'_>) -> Option<u64> {
+    adjacency_list.contains_key("svr").then(|| {
+        dfs_part_two(
+            adjacency_list,
+            &mut vec!["svr"],
+            Vec::new(),
+            &mut std::collections::HashMap::new(),
+        )
+    })
+}
+
+/// One device per line, like `aaa: you hhh`. Every output has to be listed
+/// itself or be `out`, so the search never gets stuck.
+pub fn parse(input: &str) -> Result<Input<'_>, aoc_lib::ParseError> {
+    use aoc_lib::parser::{literal, map, parse_lines, separated_by, seq, take_while};
+
+    let device = || take_while("a device", char::is_alphanumeric);
+    let outputs = separated_by(device(), literal(" "));
+
+    let devices = parse_lines(
+        input,
+        map(seq((device(), literal(": "), outputs)), |(src, _, dest)| {
+            (src, dest)
+        }),
+    )?;
+
+    let mut adjacency_list = Input::with_capacity(devices.len());
+    for (src, dest) in &devices {
+        if adjacency_list.insert(*src, dest.clone()).is_some() {
+            return Err(aoc_lib::ParseError::at(
+                input,
+                src,
+                format!("Device '{src}' is listed twice"),
+            ));
+        }
+    }
+    for dest in devices.iter().flat_map(|(_, dest)| dest) {
+        if *dest != END && !adjacency_list.contains_key(dest) {
+            return Err(aoc_lib::ParseError::at(
+                input,
+                dest,
+                format!("Unknown device '{dest}'"),
+            ));
+        }
+    }
+    Ok(adjacency_list)
+}
+
+pub struct Day;
+
+impl aoc_lib::Solution for Day {
+    const DAY: u8 = 11;
+
+    type Input<'a> = Input<'a>;
+
+    fn parse(input: &str) -> Result<Input<'_>, aoc_lib::ParseError> {
+        parse(input)
+    }
+
+    fn part_one(input: &Input<'_>) -> aoc_lib::Answer {
+        one(input).map_or(aoc_lib::Answer::Unsolved, Into::into)
+    }
+
+    fn part_two(input: &Input<'_>) -> aoc_lib::Answer {
+        two(input).map_or(aoc_lib::Answer::Unsolved, Into::into)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn examples() {
+        aoc_lib::examples::check::<crate::Day>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn missing_start() {
+        use aoc_lib::{Answer, Solution};
+
+        let input = crate::parse("you: out").unwrap();
+        assert_eq!(crate::Day::part_one(&input), Answer::Int(1));
+        assert_eq!(crate::Day::part_two(&input), Answer::Unsolved);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let tests = [
+            (
+                "you: aaa out\naaa: out\nyou: out",
+                3,
+                1,
+                "Device 'you' is listed twice",
+            ),
+            ("you: aaa bbb\naaa: out", 1, 10, "Unknown device 'bbb'"),
+        ];
+
+        for (input, line, column, message) in tests {
+            let err = crate::parse(input).err().unwrap();
+            assert_eq!((err.line, err.column), (line, column), "{input:?}");
+            assert_eq!(err.message, message, "{input:?}");
+        }
+    }
+}
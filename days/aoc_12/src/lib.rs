@@ -0,0 +1,319 @@
+use aoc_lib::ParseError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Point {
+    Occupied,
+    Empty,
+}
+
+impl Point {
+    fn display_character(&self) -> char {
+        match self {
+            Self::Occupied => '#',
+            Self::Empty => '.',
+        }
+    }
+}
+
+/// Specialized implementation for 3x3 areas.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Shape {
+    shape: [[Point; 3]; 3],
+}
+
+impl Shape {
+    pub fn new(shape: [[Point; 3]; 3]) -> Self {
+        Self { shape }
+    }
+
+    pub fn area(&self) -> usize {
+        self.shape
+            .iter()
+            .flat_map(|row| row.iter())
+            .filter(|point| matches!(point, Point::Occupied))
+            .count()
+    }
+
+    /// ```text
+    ///      ###      .##
+    /// ---- ##. ---> ##. ----
+    ///      .##      ###
+    /// ```
+    pub fn flip_horizontal_axis(&mut self) {
+        let rows = self.shape.len();
+        for i in 0..rows / 2 {
+            self.shape.swap(i, rows - 1 - i);
+        }
+    }
+
+    /// ###   |   ###
+    /// #.. ----> ..#
+    /// ###   |   ###
+    pub fn flip_vertical_axis(&mut self) {
+        for row in self.shape.iter_mut() {
+            row.reverse();
+        }
+    }
+
+    /// Attempts to place the shape in the grid as per its current layout.
+    /// If it is possible mutates the grid
+    pub fn remove_from_grid(
+        &self,
+        mid_x: usize,
+        mid_y: usize,
+        grid: &mut aoc_lib::Grid<Point>,
+    ) -> bool {
+        // Second pass: place the shape
+        for sy in 0..3 {
+            for sx in 0..3 {
+                if matches!(self.shape[sy][sx], Point::Occupied) {
+                    let grid_y = mid_y + sy - 1;
+                    let grid_x = mid_x + sx - 1;
+                    grid[(grid_y, grid_x)] = Point::Empty;
+                }
+            }
+        }
+
+        true
+    }
+
+    /// Attempts to place the shape in the grid as per its current layout.
+    /// If it is possible mutates the grid
+    pub fn try_place_in_grid(
+        &self,
+        mid_x: usize,
+        mid_y: usize,
+        grid: &mut aoc_lib::Grid<Point>,
+    ) -> bool {
+        // First pass: check if placement is possible
+        for sy in 0..3 {
+            for sx in 0..3 {
+                if matches!(self.shape[sy][sx], Point::Occupied) {
+                    let grid_y = mid_y + sy - 1;
+                    let grid_x = mid_x + sx - 1;
+                    if !matches!(grid[(grid_y, grid_x)], Point::Empty) {
+                        return false;
+                    }
+                }
+            }
+        }
+
+        // Second pass: place the shape
+        for sy in 0..3 {
+            for sx in 0..3 {
+                if matches!(self.shape[sy][sx], Point::Occupied) {
+                    let grid_y = mid_y + sy - 1;
+                    let grid_x = mid_x + sx - 1;
+                    grid[(grid_y, grid_x)] = Point::Occupied;
+                }
+            }
+        }
+
+        true
+    }
+}
+
+impl std::fmt::Display for Shape {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut res = String::with_capacity(self.shape[0].len() * self.shape.len());
+
+        for (idx, row) in self.shape.iter().enumerate() {
+            for c in row {
+                res.push(c.display_character());
+            }
+
+            if idx != self.shape.len() - 1 {
+                res.push('\n');
+            }
+        }
+        write!(f, "{res}",)
+    }
+}
+impl std::fmt::Display for Point {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.display_character(),)
+    }
+}
+
+pub type InputOne = (Vec<Shape>, Vec<((usize, usize), Vec<usize>)>);
+
+pub fn parse_one(input: &str) -> Result<InputOne, ParseError> {
+    use aoc_lib::parser::{
+        blank_line, blocks, char_grid, complete, literal, map, separated_by, seq, try_map, unsigned,
+    };
+
+    let grid = char_grid("'#' or '.'", |char| match char {
+        '#' => Some(Point::Occupied),
+        '.' => Some(Point::Empty),
+        _ => None,
+    });
+    let shape = try_map(grid, |grid| {
+        if (grid.width(), grid.height()) != (3, 3) {
+            return Err(format!(
+                "Expected a 3x3 shape, got {}x{}",
+                grid.width(),
+                grid.height()
+            ));
+        }
+        let rows: Vec<[Point; 3]> = grid.rows().map(|row| row.try_into().unwrap()).collect();
+        Ok(Shape::new(rows.try_into().unwrap()))
+    });
+    // Discard the id, it doesn't matter.
+    let shapes = blocks(map(
+        seq((unsigned::<usize>(), literal(":\n"), shape)),
+        |(_, _, shape)| shape,
+    ));
+
+    let region = map(
+        seq((
+            unsigned(),
+            literal("x"),
+            unsigned(),
+            literal(": "),
+            separated_by(unsigned(), literal(" ")),
+        )),
+        |(x, _, y, _, assignment)| ((x, y), assignment),
+    );
+    let regions = separated_by(region, literal("\n"));
+
+    let (shapes, _, regions) = complete(input, input, &seq((shapes, blank_line(), regions)))?;
+
+    // The regions are the last lines, one each.
+    let region_lines = input.lines().skip(input.lines().count() - regions.len());
+    for (line, (_, counts)) in region_lines.zip(&regions) {
+        if counts.len() > shapes.len() {
+            return Err(ParseError::at(
+                input,
+                line,
+                format!("Expected at most {} counts, one per shape", shapes.len()),
+            ));
+        }
+    }
+    Ok((shapes, regions))
+}
+
+// Return true if all shapes are placed. Enormous search space so does not complete for test input. Runs in
+pub fn search(
+    mut shapes_to_place: Vec<Shape>,
+    grid: &mut aoc_lib::Grid<Point>,
+    memoization: &mut std::collections::HashMap<(Vec<Shape>, aoc_lib::Grid<Point>), bool>,
+    token: &aoc_lib::budget::Token,
+) -> bool {
+    // Out of time, the caller discards the result anyway.
+    if token.is_cancelled() {
+        return false;
+    }
+
+    let key = (shapes_to_place.clone(), grid.clone());
+
+    // Check if we've already determined this state is unsolvable
+    if let Some(&result) = memoization.get(&key) {
+        return result;
+    }
+
+    // Check if we can even place all shapes.
+    let grid_area = grid.len();
+    let shape_area: usize = shapes_to_place.iter().map(|shape| shape.area()).sum();
+
+    // We could just return true if the area is smaller, that also produces teh correct result. But
+    // since placing the shapes takes 1.3 seconds lets just do it.
+    if shape_area > grid_area {
+        memoization.insert(key, false);
+        return false;
+    }
+
+    if let Some(mut shape) = shapes_to_place.pop() {
+        // Try placing the shape in all locations possibe locations.
+        for mid_y in 1..grid.height().saturating_sub(1) {
+            for mid_x in 1..grid.width().saturating_sub(1) {
+                // Try all orientations of the 3x3 shape:
+                for _ in 0..2 {
+                    for _ in 0..4 {
+                        if shape.try_place_in_grid(mid_x, mid_y, grid) {
+                            if search(shapes_to_place.clone(), grid, memoization, token) {
+                                return true;
+                            }
+                            shape.remove_from_grid(mid_x, mid_y, grid);
+                        }
+                        aoc_lib::rotate_90_cw_2d_array_new(&mut shape.shape);
+                    }
+                    shape.flip_horizontal_axis();
+                }
+            }
+        }
+        memoization.insert(key, false);
+    } else {
+        // Shapes to place = empty, all has been placed correctly.
+        return true;
+    }
+    false
+}
+
+pub fn one(input: &InputOne) -> usize {
+    let mut sum = 0;
+
+    let (shapes, regions) = input;
+    let token = aoc_lib::budget::token();
+
+    // We can place the shapes in any order. As long as we try all rotations and flips on all locations for all shapes.
+    let mut progress = aoc_lib::progress::Reporter::new("regions", regions.len());
+    for ((x, y), shapes_to_place) in regions {
+        if token.is_cancelled() {
+            break;
+        }
+
+        let shapes_to_place: Vec<_> = shapes_to_place
+            .iter()
+            .enumerate()
+            .flat_map(|(idx, num)| (0..*num).map(|_| shapes[idx].clone()).collect::<Vec<_>>())
+            .collect();
+
+        let mut grid = aoc_lib::Grid::filled(*x, *y, Point::Empty);
+
+        // DFS searching all possibilites.
+        if search(
+            shapes_to_place,
+            &mut grid,
+            &mut std::collections::HashMap::new(),
+            &token,
+        ) {
+            sum += 1;
+        }
+        progress.inc();
+    }
+
+    sum
+}
+
+pub struct Day;
+
+impl aoc_lib::Solution for Day {
+    const DAY: u8 = 12;
+
+    type Input<'a> = InputOne;
+
+    fn parse(input: &str) -> Result<InputOne, ParseError> {
+        parse_one(input)
+    }
+
+    fn part_one(input: &InputOne) -> aoc_lib::Answer {
+        one(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn examples() {
+        aoc_lib::examples::check::<crate::Day>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn parse_too_many_counts() {
+        let input = "0:\n###\n#..\n###\n\n4x4: 1\n4x4: 0 1";
+
+        let err = crate::parse_one(input).err().unwrap();
+        assert_eq!((err.line, err.column), (7, 1));
+        assert_eq!(err.message, "Expected at most 1 counts, one per shape");
+    }
+}
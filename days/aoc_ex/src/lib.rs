@@ -0,0 +1,41 @@
+pub type Input<'a> = Vec<&'a str>;
+
+pub fn one(_input: &Input<'_>) -> u64 {
+    0
+}
+
+pub fn two(_input: &Input<'_>) -> u64 {
+    0
+}
+
+pub fn parse(input: &str) -> Input<'_> {
+    input.lines().collect()
+}
+
+pub struct Day;
+
+impl aoc_lib::Solution for Day {
+    const DAY: u8 = 0;
+
+    type Input<'a> = Input<'a>;
+
+    fn parse(input: &str) -> Result<Input<'_>, aoc_lib::ParseError> {
+        Ok(parse(input))
+    }
+
+    fn part_one(input: &Input<'_>) -> aoc_lib::Answer {
+        one(input).into()
+    }
+
+    fn part_two(input: &Input<'_>) -> aoc_lib::Answer {
+        two(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn examples() {
+        aoc_lib::examples::check::<crate::Day>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
@@ -0,0 +1,57 @@
+/// Answer to a single part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Int(i128),
+    Str(String),
+    /// The part has not been solved yet, or the day does not have it.
+    Unsolved,
+}
+
+impl std::fmt::Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Int(int) => write!(f, "{int}"),
+            Answer::Str(str) => write!(f, "{str}"),
+            Answer::Unsolved => write!(f, "Unsolved"),
+        }
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($int:ty),*) => {
+        $(
+            impl From<$int> for Answer {
+                fn from(int: $int) -> Self {
+                    Answer::Int(int.into())
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(u8, u16, u32, u64, i8, i16, i32, i64);
+
+// usize and isize do not implement Into<i128>.
+impl From<usize> for Answer {
+    fn from(int: usize) -> Self {
+        Answer::Int(int as i128)
+    }
+}
+
+impl From<isize> for Answer {
+    fn from(int: isize) -> Self {
+        Answer::Int(int as i128)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(str: String) -> Self {
+        Answer::Str(str)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(str: &str) -> Self {
+        Answer::Str(str.to_owned())
+    }
+}
@@ -0,0 +1,270 @@
+//! Cellular automata on a [`Grid`], where every cell is updated simultaneously
+//! from its own value and the values in its neighbourhood.
+
+use crate::Grid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Neighbourhood {
+    /// Every cell within the radius in both directions, the 3x3 square for radius 1.
+    Moore(usize),
+    /// Every cell within the radius in Manhattan distance, the plus shape for radius 1.
+    VonNeumann(usize),
+}
+
+impl Neighbourhood {
+    /// Offsets to every neighbour, excluding the cell itself.
+    pub fn offsets(&self) -> Vec<(isize, isize)> {
+        let (Neighbourhood::Moore(radius) | Neighbourhood::VonNeumann(radius)) = *self;
+        let radius = radius as isize;
+
+        (-radius..=radius)
+            .flat_map(|d_row| (-radius..=radius).map(move |d_col| (d_row, d_col)))
+            .filter(|&offset| offset != (0, 0))
+            .filter(|(d_row, d_col)| match self {
+                Neighbourhood::Moore(_) => true,
+                Neighbourhood::VonNeumann(_) => d_row.abs() + d_col.abs() <= radius,
+            })
+            .collect()
+    }
+}
+
+/// Values of the neighbours of a cell which are inside the grid.
+#[derive(Clone)]
+pub struct Neighbours<'a, T> {
+    grid: &'a Grid<T>,
+    pos: (usize, usize),
+    offsets: std::slice::Iter<'a, (isize, isize)>,
+}
+
+impl<'a, T> Iterator for Neighbours<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        self.offsets
+            .by_ref()
+            .find_map(|&offset| self.grid.offset(self.pos, offset))
+            .map(|pos| &self.grid[pos])
+    }
+}
+
+/// Computes the next generation, returning it together with the number of
+/// cells which changed.
+pub fn step<T: PartialEq>(
+    grid: &Grid<T>,
+    neighbourhood: Neighbourhood,
+    mut rule: impl FnMut(&T, Neighbours<'_, T>) -> T,
+) -> (Grid<T>, usize) {
+    let offsets = neighbourhood.offsets();
+    let mut changed = 0;
+
+    let cells = grid
+        .iter()
+        .map(|(pos, cell)| {
+            let neighbours = Neighbours {
+                grid,
+                pos,
+                offsets: offsets.iter(),
+            };
+            let next = rule(cell, neighbours);
+            if next != *cell {
+                changed += 1;
+            }
+            next
+        })
+        .collect();
+
+    (Grid::new(grid.width(), grid.height(), cells), changed)
+}
+
+/// Outcome of running an automaton.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Run<T> {
+    pub grid: Grid<T>,
+    /// Number of generations in which at least one cell changed.
+    pub iterations: usize,
+    /// Cell changes summed over all generations.
+    pub changed: usize,
+}
+
+/// Runs a fixed number of generations.
+pub fn run<T: PartialEq>(
+    mut grid: Grid<T>,
+    neighbourhood: Neighbourhood,
+    generations: usize,
+    mut rule: impl FnMut(&T, Neighbours<'_, T>) -> T,
+) -> Run<T> {
+    let mut iterations = 0;
+    let mut total_changed = 0;
+
+    for _ in 0..generations {
+        let changed;
+        (grid, changed) = step(&grid, neighbourhood, &mut rule);
+        if changed != 0 {
+            iterations += 1;
+            total_changed += changed;
+        }
+    }
+
+    Run {
+        grid,
+        iterations,
+        changed: total_changed,
+    }
+}
+
+/// Runs until a generation does not change any cell. Rules which oscillate only
+/// stop once the part's time budget runs out.
+pub fn run_until_fixpoint<T: PartialEq>(
+    mut grid: Grid<T>,
+    neighbourhood: Neighbourhood,
+    mut rule: impl FnMut(&T, Neighbours<'_, T>) -> T,
+) -> Run<T> {
+    let mut iterations = 0;
+    let mut total_changed = 0;
+
+    let token = crate::budget::token();
+    loop {
+        let changed;
+        (grid, changed) = step(&grid, neighbourhood, &mut rule);
+        if changed == 0 {
+            break;
+        }
+        iterations += 1;
+        total_changed += changed;
+
+        // A generation takes long enough to read the clock after every one.
+        if token.is_cancelled_now() {
+            break;
+        }
+    }
+
+    Run {
+        grid,
+        iterations,
+        changed: total_changed,
+    }
+}
+
+/// Updates cells one at a time until nothing changes, using a worklist so only
+/// the neighbours of changed cells are examined again instead of rescanning the
+/// whole grid every generation. Returns the number of changed cells.
+///
+/// Updates are applied immediately rather than generation by generation, so
+/// this only reaches the same fixpoint as [`run_until_fixpoint`] for monotone
+/// rules, such as cells only ever being removed. The neighbourhood has to be
+/// symmetric, which both kinds are.
+pub fn propagate<T: PartialEq>(
+    grid: &mut Grid<T>,
+    neighbourhood: Neighbourhood,
+    mut rule: impl FnMut(&T, Neighbours<'_, T>) -> T,
+) -> usize {
+    let offsets = neighbourhood.offsets();
+    let mut queue: std::collections::VecDeque<_> = grid.positions().collect();
+    let mut queued = Grid::filled(grid.width(), grid.height(), true);
+    let mut changed = 0;
+
+    while let Some(pos) = queue.pop_front() {
+        queued[pos] = false;
+
+        let neighbours = Neighbours {
+            grid,
+            pos,
+            offsets: offsets.iter(),
+        };
+        let next = rule(&grid[pos], neighbours);
+        if next == grid[pos] {
+            continue;
+        }
+        grid[pos] = next;
+        changed += 1;
+
+        for neighbour in grid.neighbours(pos, &offsets) {
+            if !queued[neighbour] {
+                queued[neighbour] = true;
+                queue.push_back(neighbour);
+            }
+        }
+    }
+
+    changed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn life(&alive: &bool, neighbours: Neighbours<'_, bool>) -> bool {
+        let alive_neighbours = neighbours.filter(|&&neighbour| neighbour).count();
+        alive_neighbours == 3 || (alive && alive_neighbours == 2)
+    }
+
+    fn parse(lines: &[&str]) -> Grid<bool> {
+        Grid::parse(lines, |c| c == '#')
+    }
+
+    #[test]
+    fn test_offsets() {
+        assert_eq!(Neighbourhood::Moore(1).offsets().len(), 8);
+        assert_eq!(Neighbourhood::Moore(2).offsets().len(), 24);
+        assert_eq!(Neighbourhood::VonNeumann(1).offsets().len(), 4);
+        assert_eq!(Neighbourhood::VonNeumann(2).offsets().len(), 12);
+    }
+
+    #[test]
+    fn test_blinker() {
+        let blinker = parse(&[".....", "..#..", "..#..", "..#..", "....."]);
+
+        let (next, changed) = step(&blinker, Neighbourhood::Moore(1), life);
+        assert_eq!(next, parse(&[".....", ".....", ".###.", ".....", "....."]));
+        assert_eq!(changed, 4);
+
+        let run = run(blinker.clone(), Neighbourhood::Moore(1), 2, life);
+        assert_eq!(run.grid, blinker);
+        assert_eq!(run.iterations, 2);
+        assert_eq!(run.changed, 8);
+    }
+
+    #[test]
+    fn test_fixpoint() {
+        // A block is stable, the lone cell dies in the first generation.
+        let grid = parse(&["##...", "##...", ".....", "....#"]);
+
+        let run = run_until_fixpoint(grid, Neighbourhood::Moore(1), life);
+        assert_eq!(run.grid, parse(&["##...", "##...", ".....", "....."]));
+        assert_eq!(run.iterations, 1);
+        assert_eq!(run.changed, 1);
+    }
+
+    #[test]
+    fn test_fixpoint_out_of_budget() {
+        // A blinker never settles, so only the budget stops it, counting the
+        // generation it was applied in.
+        let blinker = parse(&[".....", "..#..", "..#..", "..#..", "....."]);
+
+        let mut counts = None;
+        let outcome = crate::budget::run(Some(std::time::Duration::ZERO), || {
+            let run = run_until_fixpoint(blinker.clone(), Neighbourhood::Moore(1), life);
+            counts = Some((run.iterations, run.changed));
+        });
+
+        assert!(outcome.is_err());
+        assert_eq!(counts, Some((1, 4)));
+    }
+
+    #[test]
+    fn test_propagate_matches_fixpoint() {
+        // Erodes cells with fewer than three neighbours, which takes several
+        // generations to peel the shape from the outside in.
+        let erode = |&cell: &bool, neighbours: Neighbours<'_, bool>| {
+            cell && neighbours.filter(|&&neighbour| neighbour).count() >= 3
+        };
+        let grid = parse(&["####.", "#####", ".####", "..###", "#...#"]);
+
+        let run = run_until_fixpoint(grid.clone(), Neighbourhood::Moore(1), erode);
+
+        let mut propagated = grid;
+        let changed = propagate(&mut propagated, Neighbourhood::Moore(1), erode);
+        assert_eq!(propagated, run.grid);
+        assert_eq!(changed, run.changed);
+    }
+}
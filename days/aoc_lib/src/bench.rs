@@ -0,0 +1,461 @@
+//! Criterion style benchmarking of the parse and part phases of a day.
+//!
+//! Every phase is first run repeatedly for a warm-up period, which also gives an
+//! estimate of how long a single iteration takes. That estimate decides how many
+//! iterations fit into the measurement period, each of them timed separately.
+//!
+//! Each phase, warm-up included, gets the same time budget as in `run`. Once it
+//! has passed no new iterations are started, and phases which noticed they ran
+//! out of it are reported as timed out and left out of the statistics.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crate::{
+    Answer, ParseError, Solution,
+    budget::{self, TimedOut},
+    report::Part,
+};
+
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub warm_up: Duration,
+    pub measurement: Duration,
+    pub min_iterations: usize,
+    pub max_iterations: usize,
+    /// Wall clock time per phase, `None` for no limit.
+    pub budget: Option<Duration>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            warm_up: Duration::from_millis(500),
+            measurement: Duration::from_secs(2),
+            min_iterations: 10,
+            max_iterations: 100_000,
+            budget: Some(crate::runner::DEFAULT_BUDGET),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    Parse,
+    Part(Part),
+}
+
+impl std::fmt::Display for Phase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "Parse"),
+            Phase::Part(part) => write!(f, "{part}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub iterations: usize,
+    pub mean: Duration,
+    pub median: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn new(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "Need at least one sample");
+        samples.sort_unstable();
+
+        let iterations = samples.len();
+        let mean = samples.iter().sum::<Duration>() / iterations as u32;
+        let median = if iterations.is_multiple_of(2) {
+            (samples[iterations / 2 - 1] + samples[iterations / 2]) / 2
+        } else {
+            samples[iterations / 2]
+        };
+
+        let variance = samples
+            .iter()
+            .map(|sample| (sample.as_secs_f64() - mean.as_secs_f64()).powi(2))
+            .sum::<f64>()
+            / iterations as f64;
+
+        Self {
+            iterations,
+            mean,
+            median,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+/// Times `f` as configured, with fewer iterations if the budget runs out.
+/// Fails if `f` noticed it ran out of the budget.
+pub fn measure(config: &Config, mut f: impl FnMut()) -> Result<Stats, TimedOut> {
+    budget::run(config.budget, || {
+        let now = Instant::now();
+        let mut warm_up_iterations = 0;
+        loop {
+            f();
+            warm_up_iterations += 1;
+            if budget::cancelled() {
+                return None;
+            }
+            if now.elapsed() >= config.warm_up || budget::deadline_passed() {
+                break;
+            }
+        }
+        let per_iteration = now.elapsed() / warm_up_iterations;
+
+        let iterations = (config.measurement.as_nanos() / per_iteration.as_nanos().max(1))
+            .clamp(config.min_iterations as u128, config.max_iterations as u128);
+
+        let mut samples = Vec::with_capacity(iterations as usize);
+        for _ in 0..iterations {
+            let now = Instant::now();
+            f();
+            samples.push(now.elapsed());
+            if budget::cancelled() {
+                return None;
+            }
+            if budget::deadline_passed() {
+                break;
+            }
+        }
+
+        Some(Stats::new(samples))
+    })
+    .map(|stats| stats.expect("Only stops early when out of time"))
+}
+
+#[derive(Debug, Clone)]
+pub struct DayBench {
+    pub day: u8,
+    pub phases: Vec<(Phase, Result<Stats, TimedOut>)>,
+}
+
+/// Benchmarks parsing and both parts. Unsolved parts are skipped.
+pub fn bench<S: Solution>(input: &str, config: &Config) -> Result<DayBench, ParseError> {
+    // Parse once up front so invalid input is reported instead of benchmarked.
+    let parsed = crate::runner::parse::<S>(input)?;
+
+    let mut phases = vec![(
+        Phase::Parse,
+        measure(config, || {
+            let _ = black_box(S::parse(black_box(input)));
+        }),
+    )];
+
+    for (part, solve) in [
+        (Part::One, S::part_one as crate::PartFn<S::Input<'_>>),
+        (Part::Two, S::part_two),
+    ] {
+        let stats = match budget::run(config.budget, || solve(&parsed)) {
+            Ok(Answer::Unsolved) => continue,
+            Ok(_) => measure(config, || {
+                black_box(solve(black_box(&parsed)));
+            }),
+            Err(timed_out) => Err(timed_out),
+        };
+        phases.push((Phase::Part(part), stats));
+    }
+
+    Ok(DayBench {
+        day: S::DAY,
+        phases,
+    })
+}
+
+/// Nearest directory up from where `aoc` is run whose `Cargo.toml` declares the
+/// workspace, or the current directory outside of one.
+pub fn workspace_root() -> std::path::PathBuf {
+    let cwd = std::env::current_dir().unwrap_or_default();
+    cwd.ancestors()
+        .find(|dir| {
+            std::fs::read_to_string(dir.join("Cargo.toml"))
+                .is_ok_and(|manifest| manifest.contains("[workspace]"))
+        })
+        .unwrap_or(&cwd)
+        .to_path_buf()
+}
+
+/// Medians of an earlier run, keyed by day and phase.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Baseline {
+    pub medians: std::collections::BTreeMap<(u8, String), Duration>,
+}
+
+impl Baseline {
+    /// In the target directory of the `workspace_root`.
+    pub fn path(name: &str) -> std::path::PathBuf {
+        workspace_root().join(format!("target/bench_baselines/{name}.txt"))
+    }
+
+    /// Reads a saved baseline, with the file and line in the error if it is
+    /// malformed.
+    pub fn read(name: &str) -> std::io::Result<Self> {
+        let path = Self::path(name);
+        let with_path = |kind, err: &dyn std::fmt::Display| {
+            std::io::Error::new(kind, format!("{}: {err}", path.display()))
+        };
+
+        let baseline = std::fs::read_to_string(&path).map_err(|err| with_path(err.kind(), &err))?;
+        Self::parse(&baseline).map_err(|err| with_path(std::io::ErrorKind::InvalidData, &err))
+    }
+
+    /// Loads a saved baseline to compare against. One which cannot be read is
+    /// empty, with a warning.
+    pub fn load(name: &str) -> Self {
+        Self::read(name).unwrap_or_else(|err| {
+            eprintln!("Not comparing against baseline '{name}': {err}");
+            Self::default()
+        })
+    }
+
+    /// One `day phase median` line per phase, with the median in nanoseconds.
+    pub fn parse(baseline: &str) -> Result<Self, ParseError> {
+        let medians = baseline
+            .lines()
+            .map(|line| {
+                let columns: Vec<_> = line.split_ascii_whitespace().collect();
+                let [day, phase, median] = columns[..] else {
+                    return Err(ParseError::at(
+                        baseline,
+                        line,
+                        "Expected a day, phase and median",
+                    ));
+                };
+                let day = crate::parse_span(baseline, day)?;
+                let median = Duration::from_nanos(crate::parse_span(baseline, median)?);
+                Ok(((day, phase.to_owned()), median))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { medians })
+    }
+
+    /// Timed out phases keep their earlier median.
+    pub fn update(&mut self, bench: &DayBench) {
+        for (phase, stats) in &bench.phases {
+            let Ok(stats) = stats else {
+                continue;
+            };
+            self.medians
+                .insert((bench.day, phase.to_string()), stats.median);
+        }
+    }
+
+    /// Merges into any existing baseline with the same name, so benchmarking a
+    /// single day does not throw away the other days.
+    pub fn save(name: &str, benches: &[DayBench]) -> std::io::Result<()> {
+        let mut baseline = match Self::read(name) {
+            Ok(baseline) => baseline,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Self::default(),
+            Err(err) => {
+                eprintln!("Replacing baseline '{name}': {err}");
+                Self::default()
+            }
+        };
+        for bench in benches {
+            baseline.update(bench);
+        }
+
+        let path = Self::path(name);
+        std::fs::create_dir_all(path.parent().unwrap())?;
+        std::fs::write(path, baseline.to_string())
+    }
+
+    pub fn median(&self, day: u8, phase: Phase) -> Option<Duration> {
+        self.medians.get(&(day, phase.to_string())).copied()
+    }
+}
+
+impl std::fmt::Display for Baseline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for ((day, phase), median) in &self.medians {
+            writeln!(f, "{day} {phase} {}", median.as_nanos())?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Change {
+    Improved(f64),
+    Regressed(f64),
+    NoChange(f64),
+}
+
+impl Change {
+    /// Compares medians, changes within `threshold` (a fraction) count as noise.
+    pub fn new(baseline: Duration, current: Duration, threshold: f64) -> Self {
+        let change = current.as_secs_f64() / baseline.as_secs_f64() - 1.0;
+        if change > threshold {
+            Change::Regressed(change)
+        } else if change < -threshold {
+            Change::Improved(change)
+        } else {
+            Change::NoChange(change)
+        }
+    }
+}
+
+impl std::fmt::Display for Change {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Change::Improved(change) => write!(f, "{:+.1}% improved", change * 100.0),
+            Change::Regressed(change) => write!(f, "{:+.1}% REGRESSED", change * 100.0),
+            Change::NoChange(change) => write!(f, "{:+.1}% no change", change * 100.0),
+        }
+    }
+}
+
+/// Formats the results as a table, comparing against the baseline if given.
+pub fn format(bench: &DayBench, baseline: Option<&Baseline>, threshold: f64) -> String {
+    use std::fmt::Write;
+
+    let mut output = format!("Day {:02}\n", bench.day);
+    writeln!(
+        output,
+        "{:<6} {:>12} {:>12} {:>12} {:>10}  Change",
+        "Phase", "Mean", "Median", "Std dev", "Iterations"
+    )
+    .unwrap();
+
+    for (phase, stats) in &bench.phases {
+        let stats = match stats {
+            Ok(stats) => stats,
+            Err(timed_out) => {
+                writeln!(output, "{:<6} {timed_out}", phase.to_string()).unwrap();
+                continue;
+            }
+        };
+        let change = match baseline.map(|baseline| baseline.median(bench.day, *phase)) {
+            Some(Some(median)) => Change::new(median, stats.median, threshold).to_string(),
+            Some(None) => "not in baseline".into(),
+            None => String::new(),
+        };
+        let row = format!(
+            "{:<6} {:>12} {:>12} {:>12} {:>10}  {change}",
+            phase.to_string(),
+            format!("{:.2?}", stats.mean),
+            format!("{:.2?}", stats.median),
+            format!("{:.2?}", stats.stddev),
+            stats.iterations,
+        );
+        writeln!(output, "{}", row.trim_end()).unwrap();
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let samples = [4, 1, 3, 2].map(Duration::from_micros).to_vec();
+        let stats = Stats::new(samples);
+
+        assert_eq!(stats.iterations, 4);
+        assert_eq!(stats.mean, Duration::from_nanos(2500));
+        assert_eq!(stats.median, Duration::from_nanos(2500));
+        // sqrt(1.25) µs
+        assert_eq!(stats.stddev.as_nanos(), 1118);
+    }
+
+    #[test]
+    fn test_baseline_roundtrip() {
+        let bench = DayBench {
+            day: 4,
+            phases: vec![
+                (
+                    Phase::Part(Part::Two),
+                    Ok(Stats::new(vec![Duration::from_micros(10)])),
+                ),
+                (
+                    Phase::Parse,
+                    Err(TimedOut {
+                        budget: Duration::ZERO,
+                        progress: None,
+                    }),
+                ),
+            ],
+        };
+        let mut baseline = Baseline::default();
+        baseline.update(&bench);
+
+        let baseline = Baseline::parse(&baseline.to_string()).unwrap();
+        assert_eq!(
+            baseline.median(4, Phase::Part(Part::Two)),
+            Some(Duration::from_micros(10))
+        );
+        assert_eq!(baseline.median(4, Phase::Parse), None);
+    }
+
+    #[test]
+    fn test_baseline_parse_errors() {
+        let tests = [
+            (
+                "4 Two 10000\n4 Parse",
+                2,
+                1,
+                "Expected a day, phase and median",
+            ),
+            (
+                "4 Two 10000\n5 One 12 x",
+                2,
+                1,
+                "Expected a day, phase and median",
+            ),
+            ("4 Two 1.5", 1, 7, "invalid digit found in string: '1.5'"),
+            ("day Two 10", 1, 1, "invalid digit found in string: 'day'"),
+        ];
+
+        for (baseline, line, column, message) in tests {
+            let err = Baseline::parse(baseline).unwrap_err();
+            assert_eq!((err.line, err.column), (line, column), "{baseline:?}");
+            assert_eq!(err.message, message, "{baseline:?}");
+        }
+    }
+
+    #[test]
+    fn test_measure_out_of_budget() {
+        let config = Config {
+            budget: Some(Duration::ZERO),
+            ..Default::default()
+        };
+
+        // Stops after a single iteration either way.
+        let mut calls = 0;
+        let stats = measure(&config, || calls += 1).unwrap();
+        assert_eq!((calls, stats.iterations), (2, 1));
+
+        let mut calls = 0;
+        let outcome = measure(&config, || {
+            calls += 1;
+            budget::token().is_cancelled();
+        });
+        assert!(outcome.is_err());
+        assert_eq!(calls, 1);
+    }
+
+    #[test]
+    fn test_change() {
+        let baseline = Duration::from_micros(100);
+
+        assert!(matches!(
+            Change::new(baseline, Duration::from_micros(120), 0.05),
+            Change::Regressed(_)
+        ));
+        assert!(matches!(
+            Change::new(baseline, Duration::from_micros(80), 0.05),
+            Change::Improved(_)
+        ));
+        assert!(matches!(
+            Change::new(baseline, Duration::from_micros(102), 0.05),
+            Change::NoChange(_)
+        ));
+    }
+}
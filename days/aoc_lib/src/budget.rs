@@ -0,0 +1,228 @@
+//! Wall clock budget per part with cooperative cancellation.
+//!
+//! The runner gives each part a deadline. Nothing is interrupted, long
+//! searches poll a [`Token`] and give up once it is cancelled, optionally
+//! recording how far they got with [`progress`] or a `progress::Reporter`.
+//! The runner then reports the part as timed out instead of using whatever it
+//! returned.
+//!
+//! ```
+//! use aoc_lib::budget;
+//!
+//! let outcome = budget::run(Some(std::time::Duration::ZERO), || {
+//!     let token = budget::token();
+//!     for done in 0.. {
+//!         if token.is_cancelled() {
+//!             budget::progress(done, 100);
+//!             return None;
+//!         }
+//!     }
+//!     Some(42)
+//! });
+//! assert_eq!(outcome.unwrap_err().progress.unwrap().done, 0);
+//! ```
+
+use std::cell::Cell;
+use std::time::{Duration, Instant};
+
+thread_local! {
+    static DEADLINE: Cell<Option<Instant>> = const { Cell::new(None) };
+    /// Set once a token noticed the deadline passed.
+    static CANCELLED: Cell<bool> = const { Cell::new(false) };
+    static PROGRESS: Cell<Option<Progress>> = const { Cell::new(None) };
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Progress {
+    pub done: usize,
+    pub total: usize,
+}
+
+/// A part which gave up after running out of its budget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimedOut {
+    pub budget: Duration,
+    /// Last progress recorded before giving up, if any.
+    pub progress: Option<Progress>,
+}
+
+impl std::fmt::Display for TimedOut {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Timed out after {:?}", self.budget)?;
+        if let Some(Progress { done, total }) = self.progress {
+            write!(f, " at {done} of {total}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Reading the clock is slow compared to a step of most searches, so a token
+/// only does so every this many polls.
+const POLLS_PER_CHECK: u32 = 1024;
+
+/// Deadline of the running part, cheap enough to poll in a hot loop.
+#[derive(Debug, Clone)]
+pub struct Token {
+    deadline: Option<Instant>,
+    polls: Cell<u32>,
+    cancelled: Cell<bool>,
+}
+
+impl Token {
+    /// Whether the search should stop. Never true outside of `run`, and may
+    /// notice the deadline passed up to `POLLS_PER_CHECK` polls late.
+    pub fn is_cancelled(&self) -> bool {
+        let Some(deadline) = self.deadline else {
+            return false;
+        };
+        if self.cancelled.get() {
+            return true;
+        }
+
+        let polls = self.polls.get();
+        self.polls.set((polls + 1) % POLLS_PER_CHECK);
+        polls == 0 && self.check(deadline)
+    }
+
+    /// Like `is_cancelled`, but reads the clock on every call. For loops whose
+    /// iterations are too slow to skip `POLLS_PER_CHECK` of them.
+    pub fn is_cancelled_now(&self) -> bool {
+        match self.deadline {
+            Some(deadline) => self.cancelled.get() || self.check(deadline),
+            None => false,
+        }
+    }
+
+    fn check(&self, deadline: Instant) -> bool {
+        if Instant::now() < deadline {
+            return false;
+        }
+        self.cancelled.set(true);
+        CANCELLED.set(true);
+        true
+    }
+}
+
+/// Token for the part currently running.
+pub fn token() -> Token {
+    Token {
+        deadline: DEADLINE.get(),
+        polls: Cell::new(0),
+        cancelled: Cell::new(false),
+    }
+}
+
+/// Whether the deadline of the running part has passed, without cancelling it.
+/// Lets a caller stop starting new work while the part itself finished fine.
+pub fn deadline_passed() -> bool {
+    DEADLINE
+        .get()
+        .is_some_and(|deadline| Instant::now() >= deadline)
+}
+
+/// Whether a token in the running part noticed the deadline, so the part gave
+/// up and `run` will fail.
+pub fn cancelled() -> bool {
+    CANCELLED.get()
+}
+
+/// Records how far the part got, reported if it times out.
+pub fn progress(done: usize, total: usize) {
+    PROGRESS.set(Some(Progress { done, total }));
+}
+
+/// Runs `f` with `budget` to spend, or without a limit for `None`. Fails if
+/// `f` noticed it ran out of time, since its value is then incomplete.
+pub fn run<T>(budget: Option<Duration>, f: impl FnOnce() -> T) -> Result<T, TimedOut> {
+    let outer = (DEADLINE.get(), CANCELLED.get(), PROGRESS.get());
+    DEADLINE.set(budget.map(|budget| Instant::now() + budget));
+    CANCELLED.set(false);
+    PROGRESS.set(None);
+
+    let value = f();
+    let result = match (CANCELLED.get(), budget) {
+        (true, Some(budget)) => Err(TimedOut {
+            budget,
+            progress: PROGRESS.get(),
+        }),
+        _ => Ok(value),
+    };
+
+    DEADLINE.set(outer.0);
+    CANCELLED.set(outer.1);
+    PROGRESS.set(outer.2);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_finished_within_budget() {
+        let outcome = run(Some(Duration::from_secs(60)), || {
+            assert!(!token().is_cancelled());
+            1
+        });
+
+        assert_eq!(outcome, Ok(1));
+    }
+
+    #[test]
+    fn test_without_budget() {
+        assert_eq!(run(None, || token().is_cancelled()), Ok(false));
+        assert!(!token().is_cancelled());
+    }
+
+    #[test]
+    fn test_timed_out() {
+        let outcome = run(Some(Duration::ZERO), || {
+            progress(3, 10);
+            token().is_cancelled()
+        });
+
+        assert_eq!(
+            outcome,
+            Err(TimedOut {
+                budget: Duration::ZERO,
+                progress: Some(Progress { done: 3, total: 10 }),
+            })
+        );
+    }
+
+    #[test]
+    fn test_cancelled_now() {
+        let outcome = run(Some(Duration::ZERO), || {
+            let token = token();
+            // Skipped by `is_cancelled` after its first poll.
+            token.polls.set(1);
+            assert!(!token.is_cancelled());
+            assert!(token.is_cancelled_now());
+        });
+
+        assert!(outcome.is_err());
+        assert!(!token().is_cancelled_now());
+    }
+
+    #[test]
+    fn test_deadline_passed_without_cancelling() {
+        let outcome = run(Some(Duration::ZERO), || {
+            assert!(deadline_passed());
+            cancelled()
+        });
+
+        assert_eq!(outcome, Ok(false));
+        assert!(!deadline_passed());
+    }
+
+    #[test]
+    fn test_late_without_polling() {
+        // Finishing late without noticing still counts as finished.
+        let outcome = run(Some(Duration::ZERO), || {
+            std::thread::sleep(Duration::from_millis(1));
+            2
+        });
+
+        assert_eq!(outcome, Ok(2));
+    }
+}
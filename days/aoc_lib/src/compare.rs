@@ -0,0 +1,174 @@
+//! Runs every registered implementation of a day, checking that they agree and
+//! comparing how long they take.
+
+use crate::{
+    Answer, ParseError, Solution, bench, budget, budget::TimedOut, implementations, report::Part,
+};
+
+#[derive(Debug, Clone)]
+pub struct ImplementationResult {
+    pub part: Part,
+    pub name: &'static str,
+    /// Unsolved if the implementation timed out.
+    pub answer: Answer,
+    pub stats: Result<bench::Stats, TimedOut>,
+}
+
+#[derive(Debug, Clone)]
+pub struct DayComparison {
+    pub day: u8,
+    pub results: Vec<ImplementationResult>,
+}
+
+impl DayComparison {
+    /// Parts where not every implementation produced the same answer, ignoring
+    /// those which timed out.
+    pub fn disagreements(&self) -> Vec<Part> {
+        [Part::One, Part::Two]
+            .into_iter()
+            .filter(|part| {
+                let mut answers = self
+                    .results
+                    .iter()
+                    .filter(|result| result.part == *part && result.stats.is_ok())
+                    .map(|result| &result.answer);
+                let first = answers.next();
+                answers.any(|answer| Some(answer) != first)
+            })
+            .collect()
+    }
+}
+
+pub fn compare<S: Solution>(
+    input: &str,
+    config: &bench::Config,
+) -> Result<DayComparison, ParseError> {
+    let input = crate::runner::parse::<S>(input)?;
+
+    let results = [Part::One, Part::Two]
+        .into_iter()
+        .flat_map(implementations::<S>)
+        .map(|implementation| {
+            let (answer, stats) = match budget::run(config.budget, || {
+                (implementation.solve)(&input)
+            }) {
+                Ok(answer) => {
+                    let stats = bench::measure(config, || {
+                        std::hint::black_box((implementation.solve)(std::hint::black_box(&input)));
+                    });
+                    (answer, stats)
+                }
+                Err(timed_out) => (Answer::Unsolved, Err(timed_out)),
+            };
+            ImplementationResult {
+                part: implementation.part,
+                name: implementation.name,
+                answer,
+                stats,
+            }
+        })
+        .collect();
+
+    Ok(DayComparison {
+        day: S::DAY,
+        results,
+    })
+}
+
+/// Formats a table with the median time of every implementation, relative to
+/// the default one.
+pub fn format(comparison: &DayComparison) -> String {
+    use std::fmt::Write;
+
+    let disagreements = comparison.disagreements();
+
+    let mut output = format!("Day {:02}\n", comparison.day);
+    writeln!(
+        output,
+        "{:<4}  {:<16}  {:>16}  {:>12}  Relative",
+        "Part", "Implementation", "Answer", "Median"
+    )
+    .unwrap();
+
+    for part in [Part::One, Part::Two] {
+        let results: Vec<_> = comparison
+            .results
+            .iter()
+            .filter(|result| result.part == part)
+            .collect();
+        let Some(default) = results.first() else {
+            continue;
+        };
+
+        for result in &results {
+            let Ok(stats) = &result.stats else {
+                writeln!(
+                    output,
+                    "{:<4}  {:<16}  timed out",
+                    part.to_string(),
+                    result.name
+                )
+                .unwrap();
+                continue;
+            };
+            let relative = match &default.stats {
+                Ok(default) => format!(
+                    "{:.2}x",
+                    stats.median.as_secs_f64() / default.median.as_secs_f64()
+                ),
+                Err(_) => "-".into(),
+            };
+            writeln!(
+                output,
+                "{:<4}  {:<16}  {:>16}  {:>12}  {relative}",
+                part.to_string(),
+                result.name,
+                result.answer.to_string(),
+                format!("{:.2?}", stats.median),
+            )
+            .unwrap();
+        }
+
+        if disagreements.contains(&part) {
+            writeln!(output, "Part {part}: implementations DISAGREE").unwrap();
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_disagreements() {
+        let result = |part, answer: i64| ImplementationResult {
+            part,
+            name: "test",
+            answer: answer.into(),
+            stats: Ok(bench::Stats::new(vec![std::time::Duration::from_micros(1)])),
+        };
+        let timed_out = ImplementationResult {
+            part: Part::One,
+            name: "slow",
+            answer: Answer::Unsolved,
+            stats: Err(TimedOut {
+                budget: std::time::Duration::ZERO,
+                progress: None,
+            }),
+        };
+        let comparison = DayComparison {
+            day: 1,
+            results: vec![
+                result(Part::One, 1),
+                result(Part::One, 1),
+                timed_out,
+                result(Part::Two, 2),
+                result(Part::Two, 3),
+            ],
+        };
+
+        assert_eq!(comparison.disagreements(), vec![Part::Two]);
+    }
+}
@@ -0,0 +1,118 @@
+//! Coordinate compression, mapping the few distinct values of a sparse axis to
+//! dense indices so grid algorithms only pay for the values which occur.
+//!
+//! ```
+//! use aoc_lib::compress::Compressor;
+//!
+//! let xs = Compressor::new([90_000, 7, 12_345, 7]);
+//! assert_eq!(xs.values(), [7, 12_345, 90_000]);
+//! assert_eq!(xs.index(12_345), Some(1));
+//! assert_eq!(xs.value(2), 90_000);
+//!
+//! // With gap markers every cell is either a value itself or lies strictly
+//! // between two, and the ends are padded.
+//! let xs = Compressor::with_gaps([2, 3, 10]);
+//! assert_eq!(xs.values(), [1, 2, 3, 4, 9, 10, 11]);
+//! assert_eq!(xs.width(3), 5);
+//! assert_eq!(xs.cell(6), Some(3));
+//! ```
+
+use crate::point::Coordinate;
+
+/// Sorted distinct values, each starting a cell which runs up to the next.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Compressor<T> {
+    values: Vec<T>,
+}
+
+impl<T: Coordinate> Compressor<T> {
+    pub fn new(values: impl IntoIterator<Item = T>) -> Self {
+        let mut values: Vec<_> = values.into_iter().collect();
+        values.sort_unstable();
+        values.dedup();
+        Self { values }
+    }
+
+    /// Also adds `value - 1` and `value + 1` for every value, where they fit in
+    /// `T`. Whatever lies between two values then gets cells of its own, as does
+    /// the outside on either end, e.g. to flood fill around a shape.
+    pub fn with_gaps(values: impl IntoIterator<Item = T>) -> Self {
+        Self::new(
+            values
+                .into_iter()
+                .flat_map(|value| [value.offset(-1), Some(value), value.offset(1)])
+                .flatten(),
+        )
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    /// Index of a value which was compressed.
+    pub fn index(&self, value: T) -> Option<usize> {
+        self.values.binary_search(&value).ok()
+    }
+
+    /// Index of the cell containing any value, `None` if it comes before the
+    /// first one.
+    pub fn cell(&self, value: T) -> Option<usize> {
+        self.values
+            .partition_point(|&start| start <= value)
+            .checked_sub(1)
+    }
+
+    pub fn value(&self, index: usize) -> T {
+        self.values[index]
+    }
+
+    /// How many values the cell stands for, up to the start of the next one.
+    /// The last cell only stands for itself.
+    pub fn width(&self, index: usize) -> T {
+        match self.values.get(index + 1) {
+            Some(&next) => next - self.values[index],
+            None => T::default().offset(1).unwrap(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let xs = Compressor::new([5u64, 1, u64::MAX, 5]);
+
+        assert_eq!(xs.values(), [1, 5, u64::MAX]);
+        assert_eq!(xs.index(5), Some(1));
+        assert_eq!(xs.index(4), None);
+        assert_eq!(xs.width(0), 4);
+        assert_eq!(xs.width(2), 1);
+        assert!(Compressor::<i32>::new([]).is_empty());
+    }
+
+    #[test]
+    fn test_with_gaps() {
+        let xs = Compressor::with_gaps([0u8, 1, 5, 255]);
+        assert_eq!(xs.values(), [0, 1, 2, 4, 5, 6, 254, 255]);
+
+        let tests = [(0, Some(0)), (3, Some(2)), (100, Some(5)), (255, Some(7))];
+        for (value, expected) in tests {
+            assert_eq!(xs.cell(value), expected, "{value}");
+        }
+
+        let widths: Vec<_> = (0..xs.len()).map(|index| xs.width(index)).collect();
+        assert_eq!(widths, [1, 1, 2, 1, 1, 248, 1, 1]);
+
+        assert_eq!(Compressor::new([3i64]).cell(2), None);
+    }
+}
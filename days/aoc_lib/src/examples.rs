@@ -0,0 +1,140 @@
+//! Regression tests against the example inputs shipped with each day.
+//!
+//! Each day lists its examples in an `expected.txt` next to the example files,
+//! one example per line:
+//!
+//! ```text
+//! # file    one    two
+//! ex1.txt   3      6
+//! ```
+//!
+//! `-` skips a part, for example when an example only covers one of them.
+
+use crate::{Solution, implementations, report::Part};
+
+pub const MANIFEST: &str = "expected.txt";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub file: String,
+    pub one: Option<String>,
+    pub two: Option<String>,
+}
+
+impl Example {
+    pub fn expected(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.one.as_deref(),
+            Part::Two => self.two.as_deref(),
+        }
+    }
+}
+
+pub fn parse_manifest(manifest: &str) -> Vec<Example> {
+    manifest
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let columns: Vec<_> = line.split_ascii_whitespace().collect();
+            let [file, one, two] = columns[..] else {
+                panic!("Expected '<file> <one> <two>' in {MANIFEST}, got '{line}'");
+            };
+            let expected = |answer: &str| (answer != "-").then(|| answer.to_owned());
+
+            Example {
+                file: file.to_owned(),
+                one: expected(one),
+                two: expected(two),
+            }
+        })
+        .collect()
+}
+
+/// Runs every implementation against every example listed in the manifest of
+/// the crate in `crate_dir`, panicking with every mismatching answer.
+///
+/// Intended to be called with `env!("CARGO_MANIFEST_DIR")` from a test.
+pub fn check<S: Solution>(crate_dir: &str) {
+    let crate_dir = std::path::Path::new(crate_dir);
+    let manifest_path = crate_dir.join(MANIFEST);
+    let manifest = std::fs::read_to_string(&manifest_path)
+        .unwrap_or_else(|err| panic!("Could not read {}: {err}", manifest_path.display()));
+
+    let examples = parse_manifest(&manifest);
+    assert!(
+        !examples.is_empty(),
+        "No examples in {}",
+        manifest_path.display()
+    );
+
+    crate::progress::set_enabled(false);
+
+    let mut failures = Vec::new();
+    for example in examples {
+        let input = std::fs::read(crate_dir.join(&example.file))
+            .unwrap_or_else(|err| panic!("Could not read {}: {err}", example.file));
+        let parsed = crate::input::load(input).and_then(|text| {
+            let input = crate::runner::parse::<S>(&text)?;
+            Ok(check_example::<S>(&example, &input))
+        });
+        match parsed {
+            Ok(example_failures) => failures.extend(example_failures),
+            Err(err) => failures.push(format!("{}:\n{}", example.file, err.diagnostic())),
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "Day {:02} examples failed:\n{}",
+        S::DAY,
+        failures.join("\n")
+    );
+}
+
+/// Mismatches of every implementation against a single example.
+fn check_example<S: Solution>(example: &Example, input: &S::Input<'_>) -> Vec<String> {
+    let mut failures = Vec::new();
+
+    for part in [Part::One, Part::Two] {
+        let Some(expected) = example.expected(part) else {
+            continue;
+        };
+        for implementation in implementations::<S>(part) {
+            let answer = (implementation.solve)(input);
+            if answer.to_string() != expected {
+                failures.push(format!(
+                    "{} part {part} ({}): expected {expected}, got {answer}",
+                    example.file, implementation.name
+                ));
+            }
+        }
+    }
+    failures
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_manifest() {
+        let manifest = "# file one two\n\nex1.txt 3 6\n  ex2.txt - 2\n";
+
+        assert_eq!(
+            parse_manifest(manifest),
+            vec![
+                Example {
+                    file: "ex1.txt".into(),
+                    one: Some("3".into()),
+                    two: Some("6".into()),
+                },
+                Example {
+                    file: "ex2.txt".into(),
+                    one: None,
+                    two: Some("2".into()),
+                },
+            ]
+        );
+    }
+}
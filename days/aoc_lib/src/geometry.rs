@@ -0,0 +1,349 @@
+//! Closest pairs and minimum spanning trees over 3D points.
+//!
+//! The brute force variants materialise all n² pairs. The k-d tree variants
+//! only ever hold the pairs they return plus a few candidates per point.
+
+use crate::{UnionFind, point::Point3, span};
+
+/// Pair of point indices with `a < b`. Ordered by distance with ties broken by
+/// the indices, so the closest pairs and the spanning tree are unique.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Edge {
+    pub distance_squared: i64,
+    pub a: usize,
+    pub b: usize,
+}
+
+impl Edge {
+    pub fn new(points: &[Point3<i64>], a: usize, b: usize) -> Self {
+        Self {
+            distance_squared: points[a].distance_squared(points[b]),
+            a: a.min(b),
+            b: a.max(b),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Index {
+    /// Compute and sort every pair.
+    BruteForce,
+    KdTree,
+}
+
+/// The `k` closest pairs, closest first.
+pub fn closest_pairs(points: &[Point3<i64>], k: usize, index: Index) -> Vec<Edge> {
+    match index {
+        Index::BruteForce => {
+            let mut edges = all_edges(points);
+            edges.truncate(k);
+            edges
+        }
+        Index::KdTree => {
+            let tree = span::time("build k-d tree", || KdTree::new(points));
+            span::time("merge nearest neighbours", || tree.closest_pairs(k))
+        }
+    }
+}
+
+/// Edges of the minimum spanning tree, shortest first. Joining them in order
+/// reproduces the order in which Kruskal's algorithm would add them.
+pub fn minimum_spanning_tree(points: &[Point3<i64>], index: Index) -> Vec<Edge> {
+    match index {
+        Index::BruteForce => {
+            let edges = all_edges(points);
+            let _kruskal = span::enter("kruskal");
+            let mut union_find = UnionFind::new(points.len());
+            edges
+                .into_iter()
+                .filter(|edge| union_find.union(edge.a, edge.b))
+                .collect()
+        }
+        Index::KdTree => {
+            let tree = span::time("build k-d tree", || KdTree::new(points));
+            span::time("boruvka", || tree.minimum_spanning_tree())
+        }
+    }
+}
+
+fn all_edges(points: &[Point3<i64>]) -> Vec<Edge> {
+    let _span = span::enter("sort all pairs");
+    let n = points.len();
+    let mut edges = Vec::with_capacity(n * n.saturating_sub(1) / 2);
+    for a in 0..n {
+        for b in a + 1..n {
+            edges.push(Edge::new(points, a, b));
+        }
+    }
+    edges.sort_unstable();
+    edges
+}
+
+/// Implicit k-d tree. The node for the index range `lo..hi` of `order` is the
+/// point at the middle of the range, splitting on the axis given by its depth.
+pub struct KdTree<'a> {
+    points: &'a [Point3<i64>],
+    order: Vec<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Label {
+    Empty,
+    Component(usize),
+    Mixed,
+}
+
+impl<'a> KdTree<'a> {
+    pub fn new(points: &'a [Point3<i64>]) -> Self {
+        fn build(points: &[Point3<i64>], order: &mut [usize], depth: usize) {
+            if order.len() <= 1 {
+                return;
+            }
+            let mid = order.len() / 2;
+            order.select_nth_unstable_by_key(mid, |&idx| points[idx][depth % 3]);
+            let (left, right) = order.split_at_mut(mid);
+            build(points, left, depth + 1);
+            build(points, &mut right[1..], depth + 1);
+        }
+
+        let mut order: Vec<usize> = (0..points.len()).collect();
+        build(points, &mut order, 0);
+        Self { points, order }
+    }
+
+    /// The `k` points closest to the point at `idx`, excluding itself, closest
+    /// first.
+    pub fn nearest(&self, idx: usize, k: usize) -> Vec<Edge> {
+        let mut heap = std::collections::BinaryHeap::with_capacity(k + 1);
+        self.nearest_in(0, self.order.len(), 0, idx, k, &mut heap);
+        heap.into_sorted_vec()
+    }
+
+    fn nearest_in(
+        &self,
+        lo: usize,
+        hi: usize,
+        depth: usize,
+        query: usize,
+        k: usize,
+        heap: &mut std::collections::BinaryHeap<Edge>,
+    ) {
+        if lo >= hi || k == 0 {
+            return;
+        }
+        let mid = lo + (hi - lo) / 2;
+        let node = self.order[mid];
+
+        if node != query {
+            let edge = Edge::new(self.points, query, node);
+            if heap.len() < k {
+                heap.push(edge);
+            } else if edge < *heap.peek().unwrap() {
+                heap.pop();
+                heap.push(edge);
+            }
+        }
+
+        let axis = depth % 3;
+        let diff = self.points[query][axis] - self.points[node][axis];
+        let (near, far) = if diff < 0 {
+            ((lo, mid), (mid + 1, hi))
+        } else {
+            ((mid + 1, hi), (lo, mid))
+        };
+
+        self.nearest_in(near.0, near.1, depth + 1, query, k, heap);
+        // Points equal on the split axis may end up on either side, hence <=.
+        if heap.len() < k || diff * diff <= heap.peek().unwrap().distance_squared {
+            self.nearest_in(far.0, far.1, depth + 1, query, k, heap);
+        }
+    }
+
+    /// Merges the per point nearest neighbour lists, which are already sorted,
+    /// through a heap. Each list is fetched lazily, doubling its length whenever
+    /// it runs out.
+    pub fn closest_pairs(&self, k: usize) -> Vec<Edge> {
+        let n = self.points.len();
+        let mut fetched: Vec<Vec<Edge>> = (0..n).map(|idx| self.nearest(idx, 2)).collect();
+        let mut cursors = vec![0; n];
+
+        let mut heap: std::collections::BinaryHeap<_> = fetched
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, edges)| Some(std::cmp::Reverse((*edges.first()?, idx))))
+            .collect();
+
+        let mut pairs = Vec::with_capacity(k);
+        while pairs.len() < k {
+            let Some(std::cmp::Reverse((edge, owner))) = heap.pop() else {
+                break;
+            };
+            // Every pair is in the lists of both its points, only take it once.
+            if edge.a == owner {
+                pairs.push(edge);
+            }
+
+            cursors[owner] += 1;
+            if cursors[owner] == fetched[owner].len() && fetched[owner].len() < n - 1 {
+                fetched[owner] = self.nearest(owner, fetched[owner].len() * 2);
+            }
+            if let Some(&next) = fetched[owner].get(cursors[owner]) {
+                heap.push(std::cmp::Reverse((next, owner)));
+            }
+        }
+
+        pairs
+    }
+
+    /// Borůvka's algorithm. Every round each component gets joined to its
+    /// closest other component, found through the tree while skipping subtrees
+    /// which lie entirely within the querying component.
+    pub fn minimum_spanning_tree(&self) -> Vec<Edge> {
+        let n = self.points.len();
+        let mut union_find = UnionFind::new(n);
+        let mut edges = Vec::with_capacity(n.saturating_sub(1));
+        let mut labels = vec![Label::Empty; n];
+
+        while union_find.component_count() > 1 {
+            let components: Vec<_> = (0..n).map(|idx| union_find.find(idx)).collect();
+            self.label(0, n, &components, &mut labels);
+
+            let mut closest: Vec<Option<Edge>> = vec![None; n];
+            for idx in 0..n {
+                let component = components[idx];
+                self.closest_other(0, n, 0, idx, &components, &labels, &mut closest[component]);
+            }
+
+            let mut closest: Vec<_> = closest.into_iter().flatten().collect();
+            closest.sort_unstable();
+            for edge in closest {
+                if union_find.union(edge.a, edge.b) {
+                    edges.push(edge);
+                }
+            }
+        }
+
+        edges.sort_unstable();
+        edges
+    }
+
+    /// Labels every node with the component of its subtree, if it has only one.
+    fn label(&self, lo: usize, hi: usize, components: &[usize], labels: &mut [Label]) -> Label {
+        if lo >= hi {
+            return Label::Empty;
+        }
+        let mid = lo + (hi - lo) / 2;
+
+        let own = Label::Component(components[self.order[mid]]);
+        let left = self.label(lo, mid, components, labels);
+        let right = self.label(mid + 1, hi, components, labels);
+
+        let label = [left, right]
+            .into_iter()
+            .fold(own, |label, child| match child {
+                Label::Empty => label,
+                child if child == label => label,
+                _ => Label::Mixed,
+            });
+        labels[mid] = label;
+        label
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn closest_other(
+        &self,
+        lo: usize,
+        hi: usize,
+        depth: usize,
+        query: usize,
+        components: &[usize],
+        labels: &[Label],
+        closest: &mut Option<Edge>,
+    ) {
+        if lo >= hi {
+            return;
+        }
+        let mid = lo + (hi - lo) / 2;
+        if labels[mid] == Label::Component(components[query]) {
+            return;
+        }
+        let node = self.order[mid];
+
+        if components[node] != components[query] {
+            let edge = Edge::new(self.points, query, node);
+            if closest.is_none_or(|closest| edge < closest) {
+                *closest = Some(edge);
+            }
+        }
+
+        let axis = depth % 3;
+        let diff = self.points[query][axis] - self.points[node][axis];
+        let (near, far) = if diff < 0 {
+            ((lo, mid), (mid + 1, hi))
+        } else {
+            ((mid + 1, hi), (lo, mid))
+        };
+
+        self.closest_other(
+            near.0,
+            near.1,
+            depth + 1,
+            query,
+            components,
+            labels,
+            closest,
+        );
+        if closest.is_none_or(|closest| diff * diff <= closest.distance_squared) {
+            self.closest_other(far.0, far.1, depth + 1, query, components, labels, closest);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Deterministic pseudo random points, with a small range to get plenty of
+    /// ties and duplicates.
+    fn points(n: usize, range: i64) -> Vec<Point3<i64>> {
+        let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+        let mut next = || {
+            state = state
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            ((state >> 33) as i64) % range
+        };
+        (0..n)
+            .map(|_| Point3::new(next(), next(), next()))
+            .collect()
+    }
+
+    #[test]
+    fn test_closest_pairs_match_brute_force() {
+        for (n, range) in [(0, 10), (1, 10), (2, 10), (50, 5), (300, 1000)] {
+            let points = points(n, range);
+            for k in [0, 1, 10, 200] {
+                assert_eq!(
+                    closest_pairs(&points, k, Index::KdTree),
+                    closest_pairs(&points, k, Index::BruteForce),
+                    "n = {n}, range = {range}, k = {k}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_minimum_spanning_tree_matches_brute_force() {
+        for (n, range) in [(0, 10), (1, 10), (2, 10), (50, 5), (300, 1000)] {
+            let points = points(n, range);
+            let tree = minimum_spanning_tree(&points, Index::KdTree);
+
+            assert_eq!(tree.len(), n.saturating_sub(1));
+            assert_eq!(
+                tree,
+                minimum_spanning_tree(&points, Index::BruteForce),
+                "n = {n}, range = {range}"
+            );
+        }
+    }
+}
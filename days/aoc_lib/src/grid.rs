@@ -0,0 +1,301 @@
+//! Flat, row-major 2D grid indexed by `(row, col)`.
+
+use crate::ParseError;
+
+/// Offsets to the four orthogonal neighbours.
+pub const NEIGHBOURS_4: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+
+/// Offsets to all eight surrounding neighbours.
+pub const NEIGHBOURS_8: [(isize, isize); 8] = [
+    // Above
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    // Same row
+    (0, -1),
+    (0, 1),
+    // Below
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(
+            width * height,
+            cells.len(),
+            "Expected {width}x{height} cells"
+        );
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::new(width, height, vec![value; width * height])
+    }
+
+    /// Parses one row per line, mapping every character to a cell.
+    pub fn parse<S: AsRef<str>>(lines: &[S], mut f: impl FnMut(char) -> T) -> Self {
+        let width = lines
+            .first()
+            .map_or(0, |line| line.as_ref().chars().count());
+        let mut cells = Vec::with_capacity(width * lines.len());
+
+        for (row, line) in lines.iter().enumerate() {
+            let before = cells.len();
+            cells.extend(line.as_ref().chars().map(&mut f));
+            assert_eq!(cells.len() - before, width, "Row {row} is not {width} wide");
+        }
+
+        Self::new(width, lines.len(), cells)
+    }
+
+    /// Like `parse`, but reports unknown characters (where `f` returns `None`)
+    /// as not being `what`, and ragged rows, instead of panicking. The whole
+    /// input is the grid, see `parser::char_grid` for grids among other input.
+    pub fn try_parse(
+        input: &str,
+        what: &'static str,
+        f: impl Fn(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let text = input.strip_suffix('\n').unwrap_or(input);
+        if text.is_empty() {
+            return Ok(Self::new(0, 0, Vec::new()));
+        }
+        crate::parser::complete(input, text, &crate::parser::char_grid(what, f))
+    }
+
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let width = rows.first().map_or(0, Vec::len);
+        let height = rows.len();
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "Rows must be the same width"
+        );
+        Self::new(width, height, rows.into_iter().flatten().collect())
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, (row, col): (usize, usize)) -> bool {
+        row < self.height && col < self.width
+    }
+
+    pub fn get(&self, pos: (usize, usize)) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.0 * self.width + pos.1])
+    }
+
+    pub fn get_mut(&mut self, pos: (usize, usize)) -> Option<&mut T> {
+        self.contains(pos)
+            .then(|| &mut self.cells[pos.0 * self.width + pos.1])
+    }
+
+    /// Position moved by the offset, if still inside the grid.
+    pub fn offset(
+        &self,
+        (row, col): (usize, usize),
+        (d_row, d_col): (isize, isize),
+    ) -> Option<(usize, usize)> {
+        let pos = (
+            row.checked_add_signed(d_row)?,
+            col.checked_add_signed(d_col)?,
+        );
+        self.contains(pos).then_some(pos)
+    }
+
+    /// Positions at the given offsets which are inside the grid.
+    pub fn neighbours<'a>(
+        &'a self,
+        pos: (usize, usize),
+        offsets: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        offsets
+            .iter()
+            .filter_map(move |&offset| self.offset(pos, offset))
+    }
+
+    /// Orthogonal neighbours inside the grid.
+    pub fn neighbours4(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbours(pos, &NEIGHBOURS_4)
+    }
+
+    /// Orthogonal and diagonal neighbours inside the grid.
+    pub fn neighbours8(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbours(pos, &NEIGHBOURS_8)
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| (row, col)))
+    }
+
+    /// Every cell together with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks_exact panics on zero.
+        self.cells.chunks_exact(self.width.max(1))
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.width, "Column {col} out of bounds");
+        self.cells.iter().skip(col).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|col| self.column(col))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+}
+
+impl<T> std::ops::Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        assert!(
+            col < self.width,
+            "Column {col} out of bounds for width {}",
+            self.width
+        );
+        &self.cells[row * self.width + col]
+    }
+}
+
+impl<T> std::ops::IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        assert!(
+            col < self.width,
+            "Column {col} out of bounds for width {}",
+            self.width
+        );
+        &mut self.cells[row * self.width + col]
+    }
+}
+
+impl<T: std::fmt::Display> std::fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (idx, row) in self.rows().enumerate() {
+            if idx != 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        Grid::parse(&["abc", "def"], |c| c)
+    }
+
+    #[test]
+    fn test_try_parse() {
+        let digit = |c: char| c.to_digit(10);
+
+        assert_eq!(
+            Grid::try_parse("12\n34\n", "a digit", digit),
+            Ok(Grid::new(2, 2, vec![1, 2, 3, 4]))
+        );
+        assert_eq!(
+            Grid::try_parse("", "a digit", digit).map(|grid| grid.height()),
+            Ok(0)
+        );
+
+        let err = Grid::try_parse("12\n3x", "a digit", digit).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+        assert_eq!(err.message, "Expected a digit");
+
+        let err = Grid::try_parse("12\n345", "a digit", digit).unwrap_err();
+        assert_eq!((err.line, err.column, err.len), (2, 1, 3));
+
+        let err = Grid::try_parse("12\n\n34", "a digit", digit).unwrap_err();
+        assert_eq!(err.message, "Unexpected trailing input");
+    }
+
+    #[test]
+    fn test_parse_and_index() {
+        let grid = grid();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(1, 0)], 'd');
+        assert_eq!(grid.get((0, 2)), Some(&'c'));
+        assert_eq!(grid.get((0, 3)), None);
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.to_string(), "abc\ndef");
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_index_out_of_bounds_column() {
+        // Would wrap around to the next row with a naive flat index.
+        let _ = grid()[(0, 3)];
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = grid();
+
+        let corner: Vec<_> = grid.neighbours4((0, 0)).collect();
+        assert_eq!(corner, vec![(0, 1), (1, 0)]);
+
+        let mut middle: Vec<_> = grid.neighbours8((0, 1)).map(|pos| grid[pos]).collect();
+        middle.sort_unstable();
+        assert_eq!(middle, vec!['a', 'c', 'd', 'e', 'f']);
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = grid();
+
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert_eq!(grid.rows().count(), 2);
+
+        let columns: Vec<String> = grid.columns().map(|column| column.collect()).collect();
+        assert_eq!(columns, vec!["ad", "be", "cf"]);
+    }
+}
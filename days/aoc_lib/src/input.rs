@@ -0,0 +1,127 @@
+//! Loading and normalising puzzle input.
+//!
+//! Everything handed to `Solution::parse` by the runner went through `load`,
+//! so days only ever see `\n` line endings and no trailing blank lines. The
+//! raw view is the normalised string itself, `lines` and `blocks` split it up.
+
+use crate::ParseError;
+
+/// Decodes and normalises the input, reporting where it stops being UTF-8.
+pub fn load(bytes: Vec<u8>) -> Result<String, ParseError> {
+    match String::from_utf8(bytes) {
+        Ok(text) => Ok(normalise(&text)),
+        Err(err) => {
+            let bytes = err.as_bytes();
+            let offset = err.utf8_error().valid_up_to();
+
+            let line_start = bytes[..offset]
+                .iter()
+                .rposition(|&byte| byte == b'\n')
+                .map_or(0, |idx| idx + 1);
+            let line_end = bytes[offset..]
+                .iter()
+                .position(|&byte| byte == b'\n')
+                .map_or(bytes.len(), |idx| offset + idx);
+            // Valid up to the offset, so this can't fail.
+            let before = std::str::from_utf8(&bytes[line_start..offset]).unwrap();
+
+            Err(ParseError {
+                day: None,
+                line: bytes[..offset]
+                    .iter()
+                    .filter(|&&byte| byte == b'\n')
+                    .count()
+                    + 1,
+                column: before.chars().count() + 1,
+                len: 1,
+                text: String::from_utf8_lossy(&bytes[line_start..line_end])
+                    .trim_end_matches('\r')
+                    .to_owned(),
+                message: "Invalid UTF-8".to_owned(),
+            })
+        }
+    }
+}
+
+/// Strips a leading BOM, turns `\r\n` into `\n` and drops the trailing newline
+/// along with any blank lines after the last line with content. Whitespace at
+/// the end of that last line is kept since some days align on columns.
+pub fn normalise(text: &str) -> String {
+    let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+    let mut text = text.replace("\r\n", "\n");
+
+    let content_end = text.trim_end().len();
+    let end = text[content_end..]
+        .find('\n')
+        .map_or(text.len(), |idx| content_end + idx);
+    text.truncate(end);
+    text
+}
+
+/// One line at a time.
+pub fn lines(input: &str) -> std::str::Lines<'_> {
+    input.lines()
+}
+
+/// Groups of lines separated by one or more blank lines, without the line
+/// ending of their last line. The blocks are slices of `input`, so they can be
+/// passed to `ParseError::at`.
+pub fn blocks(input: &str) -> impl Iterator<Item = &str> {
+    let mut lines = input
+        .split_inclusive('\n')
+        .scan(0, |offset, line| {
+            let start = *offset;
+            *offset += line.len();
+            Some((start, line))
+        })
+        .peekable();
+    let is_blank = |(_, line): &(usize, &str)| line.trim().is_empty();
+
+    std::iter::from_fn(move || {
+        while lines.next_if(is_blank).is_some() {}
+
+        let (start, first) = lines.next()?;
+        let mut end = start + first.trim_end_matches(['\n', '\r']).len();
+        while let Some((offset, line)) = lines.next_if(|line| !is_blank(line)) {
+            end = offset + line.trim_end_matches(['\n', '\r']).len();
+        }
+        Some(&input[start..end])
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalise() {
+        let tests = [
+            ("1\n2\n", "1\n2"),
+            ("1\r\n2\r\n\r\n", "1\n2"),
+            ("\u{feff}1\n2", "1\n2"),
+            ("1 \n 2  \n  \n\n", "1 \n 2  "),
+            ("", ""),
+            ("\n\n", ""),
+        ];
+
+        for (text, expected) in tests {
+            assert_eq!(normalise(text), expected, "{text:?}");
+        }
+    }
+
+    #[test]
+    fn test_load_invalid_utf8() {
+        let err = load(b"ab\ncd\xffe\n".to_vec()).unwrap_err();
+
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(err.text, "cd\u{fffd}e");
+    }
+
+    #[test]
+    fn test_blocks() {
+        let input = "a\nb\n\n\nc\n  \nd";
+
+        assert_eq!(blocks(input).collect::<Vec<_>>(), ["a\nb", "c", "d"]);
+        assert_eq!(blocks("").count(), 0);
+    }
+}
@@ -1,3 +1,33 @@
+mod answer;
+pub mod automaton;
+pub mod bench;
+pub mod budget;
+pub mod compare;
+pub mod compress;
+pub mod examples;
+pub mod geometry;
+mod grid;
+pub mod input;
+pub mod log;
+mod parse_error;
+pub mod parser;
+pub mod point;
+pub mod polygon;
+pub mod prefix_sum;
+pub mod progress;
+pub mod report;
+pub mod runner;
+pub mod scan;
+mod solution;
+pub mod span;
+mod union_find;
+
+pub use answer::Answer;
+pub use grid::{Grid, NEIGHBOURS_4, NEIGHBOURS_8};
+pub use parse_error::{ParseError, parse_span};
+pub use solution::{Implementation, PartFn, Solution, implementations};
+pub use union_find::UnionFind;
+
 /// Adds a padding layer of dots around the schematic ensuring
 /// we do not have to deal with the edges.
 pub fn pad_input<T: Clone>(mut input: Vec<Vec<T>>, character: T) -> Vec<Vec<T>> {
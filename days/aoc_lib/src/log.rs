@@ -0,0 +1,110 @@
+//! Leveled logging to stderr, for dumping intermediate state from solutions
+//! without editing them. Answers go to stdout and stay machine readable.
+//!
+//! ```
+//! let merged = [(3, 5), (10, 20)];
+//! aoc_lib::debug!("{} merged intervals: {merged:?}", merged.len());
+//! ```
+//!
+//! Prints `[DEBUG aoc_05] 2 merged intervals: [(3, 5), (10, 20)]` once the
+//! verbosity is at least 2, which the runner sets from `-v`/`-vv`/`-vvv`. The
+//! arguments are not even formatted below that.
+
+use std::sync::atomic::{AtomicU8, Ordering};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    /// `-v`
+    Info = 1,
+    /// `-vv`
+    Debug = 2,
+    /// `-vvv`
+    Trace = 3,
+}
+
+impl std::fmt::Display for Level {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Level::Info => write!(f, "INFO"),
+            Level::Debug => write!(f, "DEBUG"),
+            Level::Trace => write!(f, "TRACE"),
+        }
+    }
+}
+
+/// Off by default.
+static VERBOSITY: AtomicU8 = AtomicU8::new(0);
+
+/// Number of `-v`s, 0 for silence.
+pub fn set_verbosity(verbosity: u8) {
+    VERBOSITY.store(verbosity, Ordering::Relaxed);
+}
+
+pub fn enabled(level: Level) -> bool {
+    VERBOSITY.load(Ordering::Relaxed) >= level as u8
+}
+
+/// Counts the `-v`, `-vv` and `--verbose` flags.
+pub fn verbosity_from_flag(flag: &str) -> Option<u8> {
+    match flag {
+        "--verbose" => Some(1),
+        _ => {
+            let vs = flag.strip_prefix('-')?;
+            let level = u8::try_from(vs.len()).unwrap_or(u8::MAX);
+            (!vs.is_empty() && vs.chars().all(|c| c == 'v')).then_some(level)
+        }
+    }
+}
+
+/// Used by the macros, which check `enabled` first.
+#[doc(hidden)]
+pub fn write(level: Level, target: &str, args: std::fmt::Arguments<'_>) {
+    eprintln!("[{level} {target}] {args}");
+}
+
+#[macro_export]
+macro_rules! log {
+    ($level:expr, $($arg:tt)+) => {
+        if $crate::log::enabled($level) {
+            $crate::log::write($level, module_path!(), format_args!($($arg)+));
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Info, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Debug, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Trace, $($arg)+) };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verbosity_from_flag() {
+        let tests = [
+            ("-v", Some(1)),
+            ("-vv", Some(2)),
+            ("-vvv", Some(3)),
+            ("--verbose", Some(1)),
+            (&format!("-{}", "v".repeat(300)), Some(u8::MAX)),
+            ("-", None),
+            ("-q", None),
+            ("input.txt", None),
+        ];
+
+        for (flag, expected) in tests {
+            assert_eq!(verbosity_from_flag(flag), expected, "{flag}");
+        }
+    }
+}
@@ -0,0 +1,149 @@
+/// Error in the puzzle input, pointing at the offending part of a line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Filled in by the runner.
+    pub day: Option<u8>,
+    /// Starting at 1.
+    pub line: usize,
+    /// Starting at 1, counted in characters.
+    pub column: usize,
+    /// Number of characters the error covers.
+    pub len: usize,
+    /// The whole offending line.
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Error covering `span`, which has to be a slice of `input` such as the
+    /// ones returned by `lines`, `split` or `trim`.
+    pub fn at(input: &str, span: &str, message: impl Into<String>) -> Self {
+        let offset = (span.as_ptr() as usize).wrapping_sub(input.as_ptr() as usize);
+        debug_assert!(
+            offset + span.len() <= input.len(),
+            "Span '{span}' is not part of the input"
+        );
+        // Fall back to the start of the input rather than panicking while
+        // reporting another error.
+        let offset = if offset + span.len() <= input.len() {
+            offset
+        } else {
+            0
+        };
+
+        let line_start = input[..offset].rfind('\n').map_or(0, |idx| idx + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |idx| offset + idx);
+        let text = input[line_start..line_end].trim_end_matches('\r');
+
+        Self {
+            day: None,
+            line: input[..offset].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            len: span.chars().take_while(|&c| c != '\n').count(),
+            text: text.to_owned(),
+            message: message.into(),
+        }
+    }
+
+    /// Error covering `span` within a line, for parsers which only see one line.
+    /// `line` starts at 0 like the index from `enumerate`.
+    pub fn in_line(line: usize, text: &str, span: &str, message: impl Into<String>) -> Self {
+        Self {
+            line: line + 1,
+            ..Self::at(text, span, message)
+        }
+    }
+
+    pub fn with_day(self, day: u8) -> Self {
+        Self {
+            day: Some(day),
+            ..self
+        }
+    }
+
+    /// Multi line description with the line quoted and the span underlined.
+    pub fn diagnostic(&self) -> String {
+        let gutter = " ".repeat(self.line.to_string().len());
+        let location = match self.day {
+            Some(day) => format!("day {day:02}, line {}, column {}", self.line, self.column),
+            None => format!("line {}, column {}", self.line, self.column),
+        };
+
+        format!(
+            "error: {}\n{gutter}--> {location}\n{gutter} |\n{} | {}\n{gutter} | {}{}",
+            self.message,
+            self.line,
+            self.text,
+            " ".repeat(self.column - 1),
+            "^".repeat(self.len.max(1)),
+        )
+    }
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "Day {day:02}, ")?;
+        }
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses `span` with `FromStr`, pointing the error at it. `span` has to be a
+/// slice of `input`.
+pub fn parse_span<T>(input: &str, span: &str) -> Result<T, ParseError>
+where
+    T: std::str::FromStr,
+    T::Err: std::fmt::Display,
+{
+    span.parse()
+        .map_err(|err| ParseError::at(input, span, format!("{err}: '{span}'")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_at() {
+        let input = "L68\nLx0\nR14";
+        let line = input.lines().nth(1).unwrap();
+        let err = ParseError::at(input, &line[1..], "invalid digit").with_day(1);
+
+        assert_eq!((err.line, err.column, err.len), (2, 2, 2));
+        assert_eq!(err.text, "Lx0");
+        assert_eq!(
+            err.diagnostic(),
+            "error: invalid digit\n --> day 01, line 2, column 2\n  |\n2 | Lx0\n  |  ^^"
+        );
+    }
+
+    #[test]
+    fn test_at_end_of_line() {
+        let input = "1-\r\n2-3";
+        let line = input.lines().next().unwrap();
+        let (_, high) = line.split_once('-').unwrap();
+        let err = ParseError::at(input, high, "missing number");
+
+        assert_eq!((err.line, err.column, err.len), (1, 3, 0));
+        assert_eq!(err.text, "1-");
+    }
+
+    #[test]
+    fn test_parse_span() {
+        let input = "12,x4";
+        let (first, second) = input.split_once(',').unwrap();
+
+        assert_eq!(parse_span::<u64>(input, first), Ok(12));
+        let err = parse_span::<u64>(input, second).unwrap_err();
+        assert_eq!((err.line, err.column), (1, 4));
+    }
+}
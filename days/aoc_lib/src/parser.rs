@@ -0,0 +1,454 @@
+//! Small parser combinators for the puzzle input formats.
+//!
+//! A parser is anything implementing `Fn(&str) -> PResult<T>`, returning the
+//! parsed value and the rest of the input. Failures point at the offending
+//! slice of the input, and `complete`/`parse_lines` turn them into
+//! `ParseError`s with line and column.
+//!
+//! ```
+//! use aoc_lib::parser::{complete, delimited, literal, separated_by, unsigned};
+//!
+//! let input = "{3,5,4}";
+//! let numbers = delimited(literal("{"), separated_by(unsigned(), literal(",")), literal("}"));
+//! assert_eq!(complete(input, input, &numbers), Ok(vec![3_u32, 5, 4]));
+//! ```
+//!
+//! Repetitions backtrack: `many` and `separated_by` stop at the first item
+//! which fails to parse and leave the input from there on untouched. They
+//! remember the furthest such failure though, and `complete` reports it when
+//! parsing stops short of it, so a malformed item is not reported as trailing
+//! input at the separator before it.
+
+use std::cell::RefCell;
+
+use crate::{Grid, ParseError};
+
+thread_local! {
+    /// Furthest failure a repetition backtracked from in the running
+    /// `complete`, as the address and length of its span and its message.
+    static FURTHEST: RefCell<Option<(usize, usize, String)>> = const { RefCell::new(None) };
+}
+
+pub type PResult<'a, T> = Result<(T, &'a str), Failure<'a>>;
+
+/// Where and why a parser failed. `span` is a slice of the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Failure<'a> {
+    pub span: &'a str,
+    pub message: String,
+}
+
+impl<'a> Failure<'a> {
+    pub fn new(span: &'a str, message: impl Into<String>) -> Self {
+        Self {
+            span,
+            message: message.into(),
+        }
+    }
+
+    /// Failure pointing at the next character of `input`.
+    fn next_char(input: &'a str, message: impl Into<String>) -> Self {
+        let len = input.chars().next().map_or(0, char::len_utf8);
+        Self::new(&input[..len], message)
+    }
+}
+
+pub trait Parser<'a, T> {
+    fn parse(&self, input: &'a str) -> PResult<'a, T>;
+}
+
+impl<'a, T, F> Parser<'a, T> for F
+where
+    F: Fn(&'a str) -> PResult<'a, T>,
+{
+    fn parse(&self, input: &'a str) -> PResult<'a, T> {
+        self(input)
+    }
+}
+
+/// Remembers a failure a repetition backtracked from, if it is the furthest.
+fn backtracked(failure: Failure) {
+    let start = failure.span.as_ptr() as usize;
+    FURTHEST.with_borrow_mut(|furthest| {
+        if furthest
+            .as_ref()
+            .is_none_or(|&(furthest, ..)| start > furthest)
+        {
+            *furthest = Some((start, failure.span.len(), failure.message));
+        }
+    });
+}
+
+/// The part of `input` a parser consumed, given what it left over.
+fn consumed<'a>(input: &'a str, rest: &'a str) -> &'a str {
+    &input[..input.len() - rest.len()]
+}
+
+/// Parses all of `text`, which has to be a slice of `input`.
+pub fn complete<'a, T>(
+    input: &'a str,
+    text: &'a str,
+    parser: &impl Parser<'a, T>,
+) -> Result<T, ParseError> {
+    let outer = FURTHEST.take();
+    let result = parser.parse(text);
+    let furthest = FURTHEST.replace(outer);
+
+    let failure = match result {
+        Ok((value, "")) => return Ok(value),
+        Ok((_, rest)) => Failure::new(rest, "Unexpected trailing input"),
+        Err(failure) => failure,
+    };
+    // Parsing stopped before the furthest failure, which then explains why.
+    let text_start = text.as_ptr() as usize;
+    let failure = match furthest {
+        Some((start, len, message))
+            if start > failure.span.as_ptr() as usize && start + len <= text_start + text.len() =>
+        {
+            Failure::new(&text[start - text_start..][..len], message)
+        }
+        _ => failure,
+    };
+    Err(ParseError::at(input, failure.span, failure.message))
+}
+
+/// Parses every line of `input` in full.
+pub fn parse_lines<'a, T>(
+    input: &'a str,
+    parser: impl Parser<'a, T>,
+) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .map(|line| complete(input, line, &parser))
+        .collect()
+}
+
+pub fn literal<'a>(expected: &'static str) -> impl Parser<'a, &'a str> {
+    move |input: &'a str| match input.strip_prefix(expected) {
+        Some(rest) => Ok((consumed(input, rest), rest)),
+        None => Err(Failure::next_char(input, format!("Expected '{expected}'"))),
+    }
+}
+
+fn digits(input: &str) -> &str {
+    let len = input
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(input.len());
+    &input[..len]
+}
+
+fn number<'a, T>(input: &'a str, number: &'a str) -> PResult<'a, T>
+where
+    T: std::str::FromStr,
+    T::Err: std::fmt::Display,
+{
+    match number.parse() {
+        Ok(value) => Ok((value, &input[number.len()..])),
+        Err(err) => Err(Failure::new(number, format!("{err}: '{number}'"))),
+    }
+}
+
+/// Digits without a sign.
+pub fn unsigned<'a, T>() -> impl Parser<'a, T>
+where
+    T: std::str::FromStr,
+    T::Err: std::fmt::Display,
+{
+    move |input: &'a str| {
+        let digits = digits(input);
+        if digits.is_empty() {
+            return Err(Failure::next_char(input, "Expected a number"));
+        }
+        number(input, digits)
+    }
+}
+
+/// Digits with an optional leading `-` or `+`.
+pub fn signed<'a, T>() -> impl Parser<'a, T>
+where
+    T: std::str::FromStr,
+    T::Err: std::fmt::Display,
+{
+    move |input: &'a str| {
+        let sign = usize::from(input.starts_with(['-', '+']));
+        let digits = digits(&input[sign..]);
+        if digits.is_empty() {
+            return Err(Failure::next_char(&input[sign..], "Expected a number"));
+        }
+        number(input, &input[..sign + digits.len()])
+    }
+}
+
+/// A single character, mapped by `f`. `what` describes the accepted characters
+/// for the error.
+pub fn cell<'a, T>(what: &'static str, f: impl Fn(char) -> Option<T>) -> impl Parser<'a, T> {
+    move |input: &'a str| {
+        let mut chars = input.chars();
+        match chars.next().and_then(&f) {
+            Some(value) => Ok((value, chars.as_str())),
+            None => Err(Failure::next_char(input, format!("Expected {what}"))),
+        }
+    }
+}
+
+/// One or more characters matching `f`.
+pub fn take_while<'a>(what: &'static str, f: impl Fn(char) -> bool) -> impl Parser<'a, &'a str> {
+    move |input: &'a str| {
+        let len = input.find(|c| !f(c)).unwrap_or(input.len());
+        if len == 0 {
+            return Err(Failure::next_char(input, format!("Expected {what}")));
+        }
+        Ok((&input[..len], &input[len..]))
+    }
+}
+
+pub fn map<'a, T, U>(parser: impl Parser<'a, T>, f: impl Fn(T) -> U) -> impl Parser<'a, U> {
+    move |input: &'a str| {
+        let (value, rest) = parser.parse(input)?;
+        Ok((f(value), rest))
+    }
+}
+
+/// Like `map`, but a failing `f` fails on everything `parser` consumed.
+pub fn try_map<'a, T, U>(
+    parser: impl Parser<'a, T>,
+    f: impl Fn(T) -> Result<U, String>,
+) -> impl Parser<'a, U> {
+    move |input: &'a str| {
+        let (value, rest) = parser.parse(input)?;
+        match f(value) {
+            Ok(value) => Ok((value, rest)),
+            Err(message) => Err(Failure::new(consumed(input, rest), message)),
+        }
+    }
+}
+
+/// Zero or more.
+pub fn many<'a, T>(item: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    move |mut input: &'a str| {
+        let mut items = Vec::new();
+        loop {
+            match item.parse(input) {
+                // Nothing consumed, this would never end.
+                Ok((_, rest)) if rest.len() == input.len() => break,
+                Ok((value, rest)) => {
+                    items.push(value);
+                    input = rest;
+                }
+                Err(failure) => {
+                    backtracked(failure);
+                    break;
+                }
+            }
+        }
+        Ok((items, input))
+    }
+}
+
+/// One or more items with `separator` in between.
+pub fn separated_by<'a, T, S>(
+    item: impl Parser<'a, T>,
+    separator: impl Parser<'a, S>,
+) -> impl Parser<'a, Vec<T>> {
+    move |input: &'a str| {
+        let (first, mut input) = item.parse(input)?;
+        let mut items = vec![first];
+        loop {
+            match separator
+                .parse(input)
+                .and_then(|(_, rest)| item.parse(rest))
+            {
+                Ok((value, rest)) => {
+                    items.push(value);
+                    input = rest;
+                }
+                Err(failure) => {
+                    backtracked(failure);
+                    break;
+                }
+            }
+        }
+        Ok((items, input))
+    }
+}
+
+/// `parser` surrounded by `open` and `close`, keeping only its value.
+pub fn delimited<'a, O, T, C>(
+    open: impl Parser<'a, O>,
+    parser: impl Parser<'a, T>,
+    close: impl Parser<'a, C>,
+) -> impl Parser<'a, T> {
+    move |input: &'a str| {
+        let (_, input) = open.parse(input)?;
+        let (value, input) = parser.parse(input)?;
+        let (_, input) = close.parse(input)?;
+        Ok((value, input))
+    }
+}
+
+/// A tuple of parsers run one after the other.
+pub trait Sequence<'a, T> {
+    fn parse_sequence(&self, input: &'a str) -> PResult<'a, T>;
+}
+
+macro_rules! sequence {
+    ($($parser:ident: $value:ident),+) => {
+        impl<'a, $($value, $parser: Parser<'a, $value>),+> Sequence<'a, ($($value,)+)>
+            for ($($parser,)+)
+        {
+            #[allow(non_snake_case)]
+            fn parse_sequence(&self, input: &'a str) -> PResult<'a, ($($value,)+)> {
+                let ($($parser,)+) = self;
+                $(let ($value, input) = $parser.parse(input)?;)+
+                Ok((($($value,)+), input))
+            }
+        }
+    };
+}
+
+sequence!(A: TA, B: TB);
+sequence!(A: TA, B: TB, C: TC);
+sequence!(A: TA, B: TB, C: TC, D: TD);
+sequence!(A: TA, B: TB, C: TC, D: TD, E: TE);
+sequence!(A: TA, B: TB, C: TC, D: TD, E: TE, F: TF);
+
+/// Runs every parser of the tuple in order, returning a tuple of their values.
+pub fn seq<'a, T>(parsers: impl Sequence<'a, T>) -> impl Parser<'a, T> {
+    move |input: &'a str| parsers.parse_sequence(input)
+}
+
+/// Consecutive non-empty lines of characters mapped by `f`, all the same width.
+/// Stops before the line ending of the last row.
+pub fn char_grid<'a, T>(
+    what: &'static str,
+    f: impl Fn(char) -> Option<T>,
+) -> impl Parser<'a, Grid<T>> {
+    move |input: &'a str| {
+        let mut rest = input;
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+
+        loop {
+            let line = &rest[..rest.find('\n').unwrap_or(rest.len())];
+            if line.is_empty() {
+                break;
+            }
+            let before = cells.len();
+            for (idx, c) in line.char_indices() {
+                let value = f(c).ok_or_else(|| {
+                    Failure::new(&line[idx..idx + c.len_utf8()], format!("Expected {what}"))
+                })?;
+                cells.push(value);
+            }
+            let row_width = cells.len() - before;
+            let width = *width.get_or_insert(row_width);
+            if row_width != width {
+                return Err(Failure::new(
+                    line,
+                    format!("Expected a row {width} wide, got {row_width}"),
+                ));
+            }
+            height += 1;
+
+            rest = &rest[line.len()..];
+            match rest.strip_prefix('\n') {
+                Some(next) if !next.starts_with('\n') && !next.is_empty() => rest = next,
+                _ => break,
+            }
+        }
+
+        match width {
+            Some(width) => Ok((Grid::new(width, height, cells), rest)),
+            None => Err(Failure::next_char(input, "Expected a grid")),
+        }
+    }
+}
+
+/// One or more blank lines, including the line ending before them.
+pub fn blank_line<'a>() -> impl Parser<'a, ()> {
+    move |input: &'a str| {
+        let rest = input
+            .strip_prefix("\n\n")
+            .ok_or_else(|| Failure::next_char(input, "Expected a blank line"))?;
+        Ok(((), rest.trim_start_matches('\n')))
+    }
+}
+
+/// One or more blocks separated by blank lines.
+pub fn blocks<'a, T>(block: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    separated_by(block, blank_line())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_numbers() {
+        assert_eq!(unsigned::<u32>().parse("42,"), Ok((42, ",")));
+        assert_eq!(signed::<i32>().parse("-42"), Ok((-42, "")));
+        assert_eq!(signed::<i32>().parse("+7x"), Ok((7, "x")));
+        assert!(unsigned::<u32>().parse("-42").is_err());
+        assert!(unsigned::<u8>().parse("300").is_err());
+    }
+
+    #[test]
+    fn test_separated_by_backtracks() {
+        let numbers = separated_by(unsigned::<u32>(), literal(" "));
+
+        assert_eq!(numbers.parse("1 2 {3}"), Ok((vec![1, 2], " {3}")));
+    }
+
+    #[test]
+    fn test_furthest_failure() {
+        let input = "1x2,3x4,5y6";
+        let pair = seq((unsigned::<u32>(), literal("x"), unsigned::<u32>()));
+
+        let err = complete(input, input, &separated_by(pair, literal(","))).unwrap_err();
+        assert_eq!((err.column, err.message.as_str()), (10, "Expected 'x'"));
+
+        // Not when the failure is where parsing stopped anyway.
+        let input = "1,2 3";
+        let numbers = separated_by(unsigned::<u32>(), literal(","));
+        let err = complete(input, input, &numbers).unwrap_err();
+        assert_eq!(
+            (err.column, err.message.as_str()),
+            (4, "Unexpected trailing input")
+        );
+    }
+
+    #[test]
+    fn test_char_grid_and_blocks() {
+        let input = "#.\n.#\n\n##\n..";
+        let digit = cell("a digit", |c| c.to_digit(10));
+        let grids = blocks(char_grid("'#' or '.'", |c| Some(c == '#')));
+
+        let (grids, rest) = grids.parse(input).unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(grids.len(), 2);
+        assert_eq!(grids[1], Grid::new(2, 2, vec![true, true, false, false]));
+
+        assert_eq!(digit.parse("7a"), Ok((7, "a")));
+        let word = take_while("a word", char::is_alphabetic);
+        assert_eq!(word.parse("you: out"), Ok(("you", ": out")));
+    }
+
+    #[test]
+    fn test_error_position() {
+        let input = "[.#]\n[.x]";
+        let lights = delimited(
+            literal("["),
+            many(cell("'#' or '.'", |c| match c {
+                '#' => Some(true),
+                '.' => Some(false),
+                _ => None,
+            })),
+            literal("]"),
+        );
+
+        let err = parse_lines(input, lights).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(err.message, "Expected ']'");
+    }
+}
@@ -0,0 +1,368 @@
+//! Points on a 2D or 3D integer lattice, which double as the vectors between
+//! them.
+//!
+//! ```
+//! use aoc_lib::point::{Point2, Point3};
+//!
+//! let a: Point3<i64> = "162,817,812".parse().unwrap();
+//! let b = Point3::new(57, 618, 57);
+//! assert_eq!(a.distance_squared(b), 105 * 105 + 199 * 199 + 755 * 755);
+//!
+//! let p = Point2::new(2, 3);
+//! assert_eq!(p + Point2::new(1, -1) * 2, Point2::new(4, 1));
+//! assert_eq!(p.neighbours4().count(), 4);
+//! ```
+
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// Integer types usable as coordinates.
+pub trait Coordinate:
+    Copy
+    + Ord
+    + std::fmt::Debug
+    + Default
+    + std::hash::Hash
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+{
+    /// The absolute difference, in the same type so unsigned coordinates work.
+    fn abs_diff(self, other: Self) -> Self;
+
+    /// `None` when stepping off the edge of the type, e.g. below 0 for unsigned
+    /// coordinates.
+    fn offset(self, delta: i8) -> Option<Self>;
+}
+
+macro_rules! impl_signed_coordinate {
+    ($($int:ty),*) => {
+        $(
+            impl Coordinate for $int {
+                fn abs_diff(self, other: Self) -> Self {
+                    (self - other).abs()
+                }
+
+                fn offset(self, delta: i8) -> Option<Self> {
+                    self.checked_add(delta.into())
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_unsigned_coordinate {
+    ($($int:ty => $signed:ty),*) => {
+        $(
+            impl Coordinate for $int {
+                fn abs_diff(self, other: Self) -> Self {
+                    <$int>::abs_diff(self, other)
+                }
+
+                fn offset(self, delta: i8) -> Option<Self> {
+                    self.checked_add_signed(<$signed>::from(delta))
+                }
+            }
+        )*
+    };
+}
+
+impl_signed_coordinate!(i8, i16, i32, i64, i128, isize);
+impl_unsigned_coordinate!(u8 => i8, u16 => i16, u32 => i32, u64 => i64, u128 => i128, usize => isize);
+
+/// Offsets to the four orthogonal neighbours as `(dx, dy)`.
+const OFFSETS_4: [(i8, i8); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+/// Offsets to all eight surrounding neighbours as `(dx, dy)`, row by row.
+const OFFSETS_8: [(i8, i8); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// Offsets to the six face neighbours as `(dx, dy, dz)`.
+const OFFSETS_6: [(i8, i8, i8); 6] = [
+    (-1, 0, 0),
+    (1, 0, 0),
+    (0, -1, 0),
+    (0, 1, 0),
+    (0, 0, -1),
+    (0, 0, 1),
+];
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+/// The difference between two points, with the same operations.
+pub type Vector2<T> = Point2<T>;
+pub type Vector3<T> = Point3<T>;
+
+impl<T: Coordinate> Point2<T> {
+    pub fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+
+    pub fn manhattan(self, other: Self) -> T {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    pub fn chebyshev(self, other: Self) -> T {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    pub fn distance_squared(self, other: Self) -> T {
+        let dx = self.x.abs_diff(other.x);
+        let dy = self.y.abs_diff(other.y);
+        dx * dx + dy * dy
+    }
+
+    pub fn dot(self, other: Self) -> T {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// The z component of the 3D cross product, positive if `other` is
+    /// counterclockwise from `self` with y pointing up.
+    pub fn cross(self, other: Self) -> T {
+        self.x * other.y - self.y * other.x
+    }
+
+    fn offsets(self, offsets: &'static [(i8, i8)]) -> impl Iterator<Item = Self> {
+        offsets
+            .iter()
+            .filter_map(move |&(dx, dy)| Some(Self::new(self.x.offset(dx)?, self.y.offset(dy)?)))
+    }
+
+    /// Orthogonal neighbours, skipping any which do not fit in `T`.
+    pub fn neighbours4(self) -> impl Iterator<Item = Self> {
+        self.offsets(&OFFSETS_4)
+    }
+
+    /// Orthogonal and diagonal neighbours, skipping any which do not fit in `T`.
+    pub fn neighbours8(self) -> impl Iterator<Item = Self> {
+        self.offsets(&OFFSETS_8)
+    }
+}
+
+impl<T: Coordinate> Point3<T> {
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+
+    pub fn manhattan(self, other: Self) -> T {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y) + self.z.abs_diff(other.z)
+    }
+
+    pub fn chebyshev(self, other: Self) -> T {
+        self.x
+            .abs_diff(other.x)
+            .max(self.y.abs_diff(other.y))
+            .max(self.z.abs_diff(other.z))
+    }
+
+    pub fn distance_squared(self, other: Self) -> T {
+        let dx = self.x.abs_diff(other.x);
+        let dy = self.y.abs_diff(other.y);
+        let dz = self.z.abs_diff(other.z);
+        dx * dx + dy * dy + dz * dz
+    }
+
+    pub fn dot(self, other: Self) -> T {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    pub fn cross(self, other: Self) -> Self {
+        Self::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+
+    /// Neighbours sharing a face, skipping any which do not fit in `T`.
+    pub fn neighbours6(self) -> impl Iterator<Item = Self> {
+        OFFSETS_6.iter().filter_map(move |&(dx, dy, dz)| {
+            Some(Self::new(
+                self.x.offset(dx)?,
+                self.y.offset(dy)?,
+                self.z.offset(dz)?,
+            ))
+        })
+    }
+}
+
+macro_rules! impl_ops {
+    ($point:ident { $($axis:ident),+ }) => {
+        impl<T: Coordinate> Add for $point<T> {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                Self { $($axis: self.$axis + other.$axis),+ }
+            }
+        }
+
+        impl<T: Coordinate> Sub for $point<T> {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                Self { $($axis: self.$axis - other.$axis),+ }
+            }
+        }
+
+        impl<T: Coordinate> Mul<T> for $point<T> {
+            type Output = Self;
+
+            fn mul(self, scalar: T) -> Self {
+                Self { $($axis: self.$axis * scalar),+ }
+            }
+        }
+
+        impl<T: Coordinate + Neg<Output = T>> Neg for $point<T> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self { $($axis: -self.$axis),+ }
+            }
+        }
+
+        impl<T: Coordinate> AddAssign for $point<T> {
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+
+        impl<T: Coordinate> SubAssign for $point<T> {
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+
+        /// Coordinate by axis, 0 for x and so on, for code cycling through axes.
+        impl<T> std::ops::Index<usize> for $point<T> {
+            type Output = T;
+
+            fn index(&self, axis: usize) -> &T {
+                [$(&self.$axis),+][axis]
+            }
+        }
+
+        impl<T: std::fmt::Display> std::fmt::Display for $point<T> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                let axes = [$(self.$axis.to_string()),+];
+                write!(f, "{}", axes.join(","))
+            }
+        }
+
+        /// Parses comma separated coordinates like `1,-2,3`.
+        impl<T> std::str::FromStr for $point<T>
+        where
+            T: std::str::FromStr,
+            T::Err: std::fmt::Display,
+        {
+            type Err = String;
+
+            fn from_str(str: &str) -> Result<Self, String> {
+                const AXES: usize = [$(stringify!($axis)),+].len();
+
+                let mut coordinates = str.split(',');
+                let point = Self {
+                    $($axis: coordinates
+                        .next()
+                        .ok_or_else(|| format!("Expected {AXES} comma separated coordinates"))?
+                        .trim()
+                        .parse()
+                        .map_err(|err| format!("{err}"))?),+
+                };
+                match coordinates.next() {
+                    Some(_) => Err(format!("Expected {AXES} comma separated coordinates")),
+                    None => Ok(point),
+                }
+            }
+        }
+    };
+}
+
+impl_ops!(Point2 { x, y });
+impl_ops!(Point3 { x, y, z });
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_distances() {
+        let a = Point3::new(1, -2, 3);
+        let b = Point3::new(4, 2, -9);
+
+        assert_eq!(a.manhattan(b), 3 + 4 + 12);
+        assert_eq!(a.chebyshev(b), 12);
+        assert_eq!(a.distance_squared(b), 9 + 16 + 144);
+        assert_eq!(Point2::new(5u32, 0).distance_squared(Point2::new(2, 4)), 25);
+        assert_eq!(Point2::new(5u32, 0).manhattan(Point2::new(2, 4)), 7);
+    }
+
+    #[test]
+    fn test_products() {
+        let x = Point3::new(1, 0, 0);
+        let y = Point3::new(0, 1, 0);
+
+        assert_eq!(x.cross(y), Point3::new(0, 0, 1));
+        assert_eq!(x.dot(y), 0);
+        assert_eq!(Point2::new(1, 0).cross(Point2::new(0, 1)), 1);
+        assert_eq!(Point2::new(0, 1).cross(Point2::new(1, 0)), -1);
+        assert_eq!(Point2::new(2, 3).dot(Point2::new(4, -1)), 5);
+    }
+
+    #[test]
+    fn test_ops() {
+        let mut p = Point2::new(1, 2);
+        p += Point2::new(3, 4);
+        assert_eq!(p, Point2::new(4, 6));
+        p -= Point2::new(1, 1);
+        assert_eq!(-p * 2, Point2::new(-6, -10));
+        assert_eq!(Point3::new(7, 8, 9)[2], 9);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let origin = Point2::new(0usize, 0);
+        assert_eq!(
+            origin.neighbours4().collect::<Vec<_>>(),
+            [Point2::new(1, 0), Point2::new(0, 1)]
+        );
+        assert_eq!(origin.neighbours8().count(), 3);
+        assert_eq!(Point2::new(5i64, 5).neighbours8().count(), 8);
+        assert!(
+            Point2::new(5i64, 5)
+                .neighbours8()
+                .all(|n| n.chebyshev(Point2::new(5, 5)) == 1)
+        );
+        assert_eq!(Point3::new(0i32, 0, 0).neighbours6().count(), 6);
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("3,-4".parse(), Ok(Point2::new(3, -4)));
+        assert_eq!("162,817,812".parse(), Ok(Point3::new(162, 817, 812)));
+        assert_eq!(Point3::new(1, 2, 3).to_string(), "1,2,3");
+
+        let tests = ["1,2,3", "1", "1,x", ""];
+        for test in tests {
+            assert!(test.parse::<Point2<i64>>().is_err(), "{test}");
+        }
+        assert!("1,2".parse::<Point3<i64>>().is_err());
+    }
+}
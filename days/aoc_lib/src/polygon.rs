@@ -0,0 +1,240 @@
+//! Simple polygons on the integer lattice, given as their corners in order
+//! with the last corner connecting back to the first. Meant for loops of tiles,
+//! so most results count lattice points rather than measuring lengths.
+//!
+//! ```
+//! use aoc_lib::{point::Point2, polygon::{self, Location}};
+//!
+//! // A 4x3 rectangle of tiles, from (0, 0) to (3, 2) inclusive.
+//! let polygon = [(0, 0), (3, 0), (3, 2), (0, 2)].map(|(x, y)| Point2::new(x, y));
+//!
+//! assert_eq!(polygon::doubled_area(&polygon), 12);
+//! assert_eq!(polygon::boundary_points(&polygon), 10);
+//! assert_eq!(polygon::interior_points(&polygon), 2);
+//! assert_eq!(polygon::enclosed_points(&polygon), 12);
+//! assert_eq!(polygon::locate(Point2::new(3, 1), &polygon), Location::Boundary);
+//! ```
+
+use crate::point::Point2;
+
+type Point = Point2<i64>;
+
+/// Turning direction, with y pointing up. With y pointing down, as for rows,
+/// the two are swapped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Orientation {
+    Clockwise,
+    CounterClockwise,
+    Collinear,
+}
+
+/// Where a point lies relative to a polygon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Location {
+    Inside,
+    Boundary,
+    Outside,
+}
+
+/// Whether going from `a` to `b` to `c` turns left or right.
+pub fn orientation(a: Point, b: Point, c: Point) -> Orientation {
+    match (b - a).cross(c - a).cmp(&0) {
+        std::cmp::Ordering::Greater => Orientation::CounterClockwise,
+        std::cmp::Ordering::Less => Orientation::Clockwise,
+        std::cmp::Ordering::Equal => Orientation::Collinear,
+    }
+}
+
+/// Whether `p` lies on the segment from `a` to `b`, including its ends.
+pub fn on_segment(p: Point, a: Point, b: Point) -> bool {
+    orientation(a, p, b) == Orientation::Collinear
+        && (a.x.min(b.x)..=a.x.max(b.x)).contains(&p.x)
+        && (a.y.min(b.y)..=a.y.max(b.y)).contains(&p.y)
+}
+
+/// Whether the segments cross at a single point inside both of them. Touching
+/// at an end or overlapping along a line does not count.
+pub fn segments_cross((a1, a2): (Point, Point), (b1, b2): (Point, Point)) -> bool {
+    let o1 = orientation(a1, a2, b1);
+    let o2 = orientation(a1, a2, b2);
+    let o3 = orientation(b1, b2, a1);
+    let o4 = orientation(b1, b2, a2);
+
+    // Each has to straddle the line through the other.
+    [o1, o2, o3, o4]
+        .iter()
+        .all(|&o| o != Orientation::Collinear)
+        && o1 != o2
+        && o3 != o4
+}
+
+/// Every side of the polygon, including the one closing it.
+pub fn edges(polygon: &[Point]) -> impl Iterator<Item = (Point, Point)> + '_ {
+    polygon
+        .iter()
+        .copied()
+        .zip(polygon.iter().copied().cycle().skip(1))
+}
+
+/// Twice the signed area through the shoelace formula, which keeps it an
+/// integer. Positive for counterclockwise corners.
+pub fn doubled_signed_area(polygon: &[Point]) -> i64 {
+    edges(polygon).map(|(a, b)| a.cross(b)).sum()
+}
+
+/// Twice the area enclosed by the boundary, taking corners as points rather
+/// than tiles.
+pub fn doubled_area(polygon: &[Point]) -> i64 {
+    doubled_signed_area(polygon).abs()
+}
+
+/// Order the corners are listed in, collinear for degenerate polygons.
+pub fn winding(polygon: &[Point]) -> Orientation {
+    match doubled_signed_area(polygon).cmp(&0) {
+        std::cmp::Ordering::Greater => Orientation::CounterClockwise,
+        std::cmp::Ordering::Less => Orientation::Clockwise,
+        std::cmp::Ordering::Equal => Orientation::Collinear,
+    }
+}
+
+/// Lattice points on the boundary. For rectilinear polygons this is also the
+/// perimeter.
+pub fn boundary_points(polygon: &[Point]) -> i64 {
+    edges(polygon)
+        .map(|(a, b)| {
+            let diff = b - a;
+            crate::gcd(
+                diff.x.unsigned_abs() as usize,
+                diff.y.unsigned_abs() as usize,
+            ) as i64
+        })
+        .sum()
+}
+
+/// Lattice points strictly inside, through Pick's theorem `A = I + B/2 - 1`.
+pub fn interior_points(polygon: &[Point]) -> i64 {
+    (doubled_area(polygon) - boundary_points(polygon) + 2) / 2
+}
+
+/// Lattice points inside or on the boundary, i.e. the number of tiles covered
+/// by a loop together with everything it encloses.
+pub fn enclosed_points(polygon: &[Point]) -> i64 {
+    interior_points(polygon) + boundary_points(polygon)
+}
+
+/// Ray casting towards positive x, counting the edges crossed.
+pub fn locate(point: Point, polygon: &[Point]) -> Location {
+    let mut inside = false;
+
+    for (a, b) in edges(polygon) {
+        if on_segment(point, a, b) {
+            return Location::Boundary;
+        }
+
+        // Only edges crossing the horizontal line through the point, counting
+        // the lower end but not the upper so vertices are not counted twice.
+        if (a.y > point.y) != (b.y > point.y) {
+            // The crossing is right of the point if
+            // point.x < a.x + (point.y - a.y) * (b.x - a.x) / (b.y - a.y),
+            // multiplied out to stay in integers, which flips with the sign of dy.
+            let dy = b.y - a.y;
+            let lhs = (point.x - a.x) * dy;
+            let rhs = (point.y - a.y) * (b.x - a.x);
+
+            let crossing_right = if dy > 0 { lhs < rhs } else { lhs > rhs };
+            if crossing_right {
+                inside = !inside;
+            }
+        }
+    }
+
+    if inside {
+        Location::Inside
+    } else {
+        Location::Outside
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn polygon(corners: &[(i64, i64)]) -> Vec<Point> {
+        corners.iter().map(|&(x, y)| Point::new(x, y)).collect()
+    }
+
+    #[test]
+    fn test_counts() {
+        // The day 9 example.
+        let day_9 = polygon(&[
+            (7, 1),
+            (11, 1),
+            (11, 7),
+            (9, 7),
+            (9, 5),
+            (2, 5),
+            (2, 3),
+            (7, 3),
+        ]);
+        let triangle = polygon(&[(0, 0), (4, 0), (0, 4)]);
+
+        let tests = [
+            // (polygon, doubled area, boundary, interior, winding)
+            (&day_9, 60, 30, 16, Orientation::CounterClockwise),
+            (&triangle, 16, 12, 3, Orientation::CounterClockwise),
+        ];
+
+        for (polygon, doubled, boundary, interior, winding_) in tests {
+            assert_eq!(doubled_area(polygon), doubled, "{polygon:?}");
+            assert_eq!(boundary_points(polygon), boundary, "{polygon:?}");
+            assert_eq!(interior_points(polygon), interior, "{polygon:?}");
+            assert_eq!(enclosed_points(polygon), boundary + interior, "{polygon:?}");
+            assert_eq!(winding(polygon), winding_, "{polygon:?}");
+
+            let reversed: Vec<_> = polygon.iter().rev().copied().collect();
+            assert_eq!(doubled_signed_area(&reversed), -doubled, "{polygon:?}");
+            assert_eq!(winding(&reversed), Orientation::Clockwise, "{polygon:?}");
+        }
+    }
+
+    #[test]
+    fn test_locate() {
+        let l_shape = polygon(&[(0, 0), (4, 0), (4, 4), (2, 4), (2, 2), (0, 2)]);
+
+        let tests = [
+            ((1, 1), Location::Inside),
+            ((3, 3), Location::Inside),
+            ((2, 3), Location::Boundary),
+            ((4, 4), Location::Boundary),
+            ((0, 1), Location::Boundary),
+            ((1, 3), Location::Outside),
+            ((5, 0), Location::Outside),
+            // On the line through a vertex.
+            ((-1, 2), Location::Outside),
+            ((-1, 0), Location::Outside),
+        ];
+
+        for ((x, y), expected) in tests {
+            assert_eq!(locate(Point::new(x, y), &l_shape), expected, "({x}, {y})");
+        }
+    }
+
+    #[test]
+    fn test_segments_cross() {
+        let segment = |x1, y1, x2, y2| (Point::new(x1, y1), Point::new(x2, y2));
+
+        let tests = [
+            (segment(0, 1, 2, 1), segment(1, 0, 1, 2), true),
+            // Touching at an end.
+            (segment(0, 1, 2, 1), segment(1, 1, 1, 2), false),
+            // Overlapping.
+            (segment(0, 0, 2, 0), segment(1, 0, 3, 0), false),
+            (segment(0, 0, 1, 0), segment(2, -1, 2, 1), false),
+        ];
+
+        for (a, b, expected) in tests {
+            assert_eq!(segments_cross(a, b), expected, "{a:?} {b:?}");
+            assert_eq!(segments_cross(b, a), expected, "{a:?} {b:?}");
+        }
+    }
+}
@@ -0,0 +1,183 @@
+//! Prefix sums for O(1) sums over ranges and rectangles, once built in O(n).
+//!
+//! ```
+//! use aoc_lib::prefix_sum::PrefixSum2D;
+//!
+//! let rows = aoc_lib::pad_input(vec![vec![1, 2], vec![3, 4]], 0);
+//! let sums = PrefixSum2D::new(&rows);
+//!
+//! assert_eq!(sums.sum((0, 0), (3, 3)), 10);
+//! // The 3x3 window around the 4, in padded coordinates.
+//! assert_eq!(sums.sum((1, 1), (3, 3)), 10);
+//! assert_eq!(sums.sum((2, 1), (2, 2)), 7);
+//! ```
+//!
+//! For a coordinate compressed grid, build it with `from_fn` weighting every
+//! cell by the area it stands for, so sums are in real units again.
+
+use std::ops::{Add, RangeInclusive, Sub};
+
+/// What can be summed. Subtraction is needed to take the sums apart again.
+pub trait Summable: Copy + Default + Add<Output = Self> + Sub<Output = Self> {}
+
+impl<T: Copy + Default + Add<Output = T> + Sub<Output = T>> Summable for T {}
+
+/// Sums over inclusive index ranges of a sequence.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrefixSum<T> {
+    /// `sums[i]` is the sum of the first `i` values.
+    sums: Vec<T>,
+}
+
+impl<T: Summable> PrefixSum<T> {
+    pub fn new(values: impl IntoIterator<Item = T>) -> Self {
+        let mut sums = vec![T::default()];
+        for value in values {
+            sums.push(*sums.last().unwrap() + value);
+        }
+        Self { sums }
+    }
+
+    pub fn len(&self) -> usize {
+        self.sums.len() - 1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Sum of the values in the range, which must be inside the sequence.
+    pub fn sum(&self, range: RangeInclusive<usize>) -> T {
+        let (start, end) = range.into_inner();
+        assert!(
+            start <= end + 1 && end < self.len(),
+            "{start}..={end} out of bounds"
+        );
+        self.sums[end + 1] - self.sums[start]
+    }
+}
+
+/// Sums over inclusive rectangles of a grid, a summed-area table. Positions are
+/// `(row, col)` like everywhere else.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrefixSum2D<T> {
+    width: usize,
+    height: usize,
+    /// Row major `(height + 1) x (width + 1)`, where the entry for `(row, col)`
+    /// is the sum of everything above and left of it.
+    sums: Vec<T>,
+}
+
+impl<T: Summable> PrefixSum2D<T> {
+    /// From rows of equal length, e.g. the `Vec<Vec<T>>` of `pad_input`.
+    pub fn new<R: AsRef<[T]>>(rows: &[R]) -> Self {
+        let width = rows.first().map_or(0, |row| row.as_ref().len());
+        assert!(
+            rows.iter().all(|row| row.as_ref().len() == width),
+            "Rows differ in length"
+        );
+        Self::from_fn(rows.len(), width, |(row, col)| rows[row].as_ref()[col])
+    }
+
+    pub fn from_grid(grid: &crate::Grid<T>) -> Self {
+        Self::from_fn(grid.height(), grid.width(), |pos| grid[pos])
+    }
+
+    /// Takes every cell's value from `f`, which makes it easy to count cells
+    /// matching some condition or to weight cells.
+    pub fn from_fn(height: usize, width: usize, mut f: impl FnMut((usize, usize)) -> T) -> Self {
+        let stride = width + 1;
+        let mut sums = vec![T::default(); (height + 1) * stride];
+        for row in 0..height {
+            let mut row_sum = T::default();
+            for col in 0..width {
+                row_sum = row_sum + f((row, col));
+                sums[(row + 1) * stride + col + 1] = sums[row * stride + col + 1] + row_sum;
+            }
+        }
+        Self {
+            width,
+            height,
+            sums,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn at(&self, row: usize, col: usize) -> T {
+        self.sums[row * (self.width + 1) + col]
+    }
+
+    /// Sum of the rectangle with `top_left` and `bottom_right` as its inclusive
+    /// corners.
+    pub fn sum(&self, top_left: (usize, usize), bottom_right: (usize, usize)) -> T {
+        let ((top, left), (bottom, right)) = (top_left, bottom_right);
+        assert!(
+            top <= bottom && left <= right && bottom < self.height && right < self.width,
+            "{top_left:?} to {bottom_right:?} out of bounds"
+        );
+        self.at(bottom + 1, right + 1) + self.at(top, left)
+            - self.at(top, right + 1)
+            - self.at(bottom + 1, left)
+    }
+
+    /// Sum of the rectangle spanned by two opposite corners, in any order.
+    pub fn sum_between(&self, a: (usize, usize), b: (usize, usize)) -> T {
+        self.sum((a.0.min(b.0), a.1.min(b.1)), (a.0.max(b.0), a.1.max(b.1)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prefix_sum() {
+        let sums = PrefixSum::new([3, -1, 4, 1, 5]);
+
+        assert_eq!(sums.len(), 5);
+        assert_eq!(sums.sum(0..=4), 12);
+        assert_eq!(sums.sum(1..=3), 4);
+        assert_eq!(sums.sum(2..=2), 4);
+        assert!(PrefixSum::<u8>::new([]).is_empty());
+    }
+
+    #[test]
+    fn test_prefix_sum_2d_matches_brute_force() {
+        let rows: Vec<Vec<i64>> = (0..5)
+            .map(|row| (0..7).map(|col| (row * 7 + col) % 5 - 2).collect())
+            .collect();
+        let sums = PrefixSum2D::new(&rows);
+
+        for top in 0..5 {
+            for bottom in top..5 {
+                for left in 0..7 {
+                    for right in left..7 {
+                        let expected: i64 = rows[top..=bottom]
+                            .iter()
+                            .map(|row| row[left..=right].iter().sum::<i64>())
+                            .sum();
+                        assert_eq!(sums.sum((top, left), (bottom, right)), expected);
+                        assert_eq!(sums.sum_between((bottom, left), (top, right)), expected);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_prefix_sum_2d_from_grid() {
+        let grid = crate::Grid::parse(&["#.#", "##."], |c| u32::from(c == '#'));
+        let sums = PrefixSum2D::from_grid(&grid);
+
+        assert_eq!((sums.height(), sums.width()), (2, 3));
+        assert_eq!(sums.sum((0, 0), (1, 2)), 4);
+        assert_eq!(sums.sum((0, 1), (1, 2)), 2);
+    }
+}
@@ -0,0 +1,135 @@
+//! Progress of solutions looping over independent instances, written to
+//! stderr so it never mixes with the answers.
+//!
+//! ```
+//! let machines = [1, 2, 3];
+//! let mut progress = aoc_lib::progress::Reporter::new("machines", machines.len());
+//! for _machine in machines {
+//!     progress.inc();
+//! }
+//! ```
+//!
+//! Shows `machines: 37/150 (2.5/s, ETA 45.2s)` on a single line which is
+//! cleared once done. Only drawn when stderr is a terminal, and never after
+//! `set_enabled(false)`. The count is also what `budget` reports when a part
+//! times out.
+
+use std::io::{IsTerminal, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+static ENABLED: AtomicBool = AtomicBool::new(true);
+
+/// Redrawing more often than this only costs time.
+const REDRAW_EVERY: Duration = Duration::from_millis(100);
+
+/// Silences or re-enables drawing for every reporter created afterwards.
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+pub struct Reporter {
+    label: &'static str,
+    done: usize,
+    total: usize,
+    /// Start and last redraw, `None` when not drawing at all.
+    drawing: Option<(Instant, Instant)>,
+    drawn: bool,
+}
+
+impl Reporter {
+    pub fn new(label: &'static str, total: usize) -> Self {
+        let drawing = (ENABLED.load(Ordering::Relaxed) && std::io::stderr().is_terminal())
+            .then(|| (Instant::now(), Instant::now()));
+        crate::budget::progress(0, total);
+
+        Self {
+            label,
+            done: 0,
+            total,
+            drawing,
+            drawn: false,
+        }
+    }
+
+    pub fn inc(&mut self) {
+        self.set(self.done + 1);
+    }
+
+    pub fn set(&mut self, done: usize) {
+        self.done = done;
+        crate::budget::progress(done, self.total);
+
+        let Some((start, last_drawn)) = &mut self.drawing else {
+            return;
+        };
+        let now = Instant::now();
+        if now - *last_drawn < REDRAW_EVERY && done < self.total {
+            return;
+        }
+        *last_drawn = now;
+
+        let line = format(self.label, done, self.total, now - *start);
+        let mut stderr = std::io::stderr().lock();
+        // Progress is best effort, a closed stderr should not fail the part.
+        let _ = write!(stderr, "\r\x1b[2K{line}");
+        let _ = stderr.flush();
+        self.drawn = true;
+    }
+
+    /// Clears the line, also done when dropped.
+    pub fn finish(&mut self) {
+        if std::mem::take(&mut self.drawn) {
+            let _ = write!(std::io::stderr(), "\r\x1b[2K");
+        }
+    }
+}
+
+impl Drop for Reporter {
+    fn drop(&mut self) {
+        self.finish();
+    }
+}
+
+/// `label: done/total (rate/s, ETA ..)`, leaving out what can't be known yet.
+pub fn format(label: &str, done: usize, total: usize, elapsed: Duration) -> String {
+    let mut line = format!("{label}: {done}/{total}");
+    let rate = done as f64 / elapsed.as_secs_f64();
+    if done > 0 && done < total && rate.is_finite() {
+        let remaining = Duration::from_secs_f64((total - done) as f64 / rate);
+        line.push_str(&format!(" ({rate:.1}/s, ETA {remaining:.1?})"));
+    } else if done > 0 && rate.is_finite() {
+        line.push_str(&format!(" ({rate:.1}/s)"));
+    }
+    line
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format() {
+        assert_eq!(format("machines", 0, 10, Duration::ZERO), "machines: 0/10");
+        assert_eq!(
+            format("machines", 5, 20, Duration::from_secs(2)),
+            "machines: 5/20 (2.5/s, ETA 6.0s)"
+        );
+        assert_eq!(
+            format("machines", 20, 20, Duration::from_secs(4)),
+            "machines: 20/20 (5.0/s)"
+        );
+    }
+
+    #[test]
+    fn test_feeds_budget() {
+        let outcome = crate::budget::run(Some(Duration::ZERO), || {
+            let mut progress = Reporter::new("regions", 3);
+            progress.inc();
+            crate::budget::token().is_cancelled()
+        });
+
+        let progress = outcome.unwrap_err().progress.unwrap();
+        assert_eq!((progress.done, progress.total), (1, 3));
+    }
+}
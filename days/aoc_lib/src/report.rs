@@ -0,0 +1,262 @@
+use crate::{Answer, budget::TimedOut, span::Span};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl std::fmt::Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "One"),
+            Part::Two => write!(f, "Two"),
+        }
+    }
+}
+
+/// Answer of a single part together with how long it took to compute.
+#[derive(Debug, Clone)]
+pub struct PartResult {
+    pub part: Part,
+    /// Name of the implementation which was run, see `Solution::alternatives`.
+    pub implementation: &'static str,
+    /// Unsolved if the part timed out.
+    pub answer: Answer,
+    pub elapsed: std::time::Duration,
+    pub spans: Vec<Span>,
+    pub timed_out: Option<TimedOut>,
+    /// Message of the panic, the answer is then Unsolved.
+    pub panicked: Option<String>,
+}
+
+/// Everything produced by running a single day.
+#[derive(Debug, Clone)]
+pub struct DayReport {
+    pub day: u8,
+    pub parse: std::time::Duration,
+    pub parse_spans: Vec<Span>,
+    pub parts: [PartResult; 2],
+}
+
+impl DayReport {
+    pub fn part(&self, part: Part) -> &PartResult {
+        match part {
+            Part::One => &self.parts[0],
+            Part::Two => &self.parts[1],
+        }
+    }
+
+    /// Whether either part panicked.
+    pub fn panicked(&self) -> bool {
+        self.parts.iter().any(|part| part.panicked.is_some())
+    }
+}
+
+/// Prints the spans indented below their parent, with their share of its time.
+pub fn print_spans(spans: &[Span], parent: std::time::Duration, depth: usize) {
+    for span in spans {
+        let share = span.elapsed.as_secs_f64() / parent.as_secs_f64().max(f64::MIN_POSITIVE);
+        println!(
+            "{:indent$}{} | Elapsed: {:?} ({:.0}%)",
+            "",
+            span.name,
+            span.elapsed,
+            share * 100.0,
+            indent = 2 * depth
+        );
+        print_spans(&span.children, span.elapsed, depth + 1);
+    }
+}
+
+impl PartResult {
+    /// The answer, or why there is none.
+    pub fn outcome(&self) -> String {
+        match (&self.timed_out, &self.panicked) {
+            (Some(timed_out), _) => timed_out.to_string(),
+            (None, Some(message)) => format!("Panicked: {message}"),
+            (None, None) => self.answer.to_string(),
+        }
+    }
+}
+
+pub fn print_part(result: &PartResult) {
+    println!(
+        "{}: {} | Elapsed: {:?}",
+        result.part,
+        result.outcome(),
+        result.elapsed
+    );
+    print_spans(&result.spans, result.elapsed, 1);
+}
+
+pub fn print_day(report: &DayReport) {
+    println!("Day {:02}", report.day);
+    println!("Parse | Elapsed: {:?}", report.parse);
+    print_spans(&report.parse_spans, report.parse, 1);
+    for part in &report.parts {
+        print_part(part);
+    }
+}
+
+/// Formats the reports as a JSON array with one object per day, for anything
+/// consuming the results rather than reading them. Answers are strings since
+/// they do not always fit in a double, and `null` if unsolved. Times are in
+/// nanoseconds.
+pub fn json(reports: &[DayReport]) -> String {
+    let days: Vec<_> = reports.iter().map(day_json).collect();
+    format!("[{}]", days.join(","))
+}
+
+fn day_json(report: &DayReport) -> String {
+    let parts: Vec<_> = report.parts.iter().map(part_json).collect();
+    format!(
+        r#"{{"day":{},"parse_ns":{},"parse_spans":{},"parts":[{}]}}"#,
+        report.day,
+        report.parse.as_nanos(),
+        spans_json(&report.parse_spans),
+        parts.join(",")
+    )
+}
+
+fn part_json(result: &PartResult) -> String {
+    let answer = match &result.answer {
+        Answer::Unsolved => "null".to_owned(),
+        answer => json_string(&answer.to_string()),
+    };
+    let timed_out = match &result.timed_out {
+        Some(timed_out) => json_string(&timed_out.to_string()),
+        None => "null".to_owned(),
+    };
+    let panicked = match &result.panicked {
+        Some(message) => json_string(message),
+        None => "null".to_owned(),
+    };
+    format!(
+        r#"{{"part":{},"implementation":{},"answer":{answer},"elapsed_ns":{},"timed_out":{timed_out},"panicked":{panicked},"spans":{}}}"#,
+        json_string(&result.part.to_string()),
+        json_string(result.implementation),
+        result.elapsed.as_nanos(),
+        spans_json(&result.spans)
+    )
+}
+
+fn spans_json(spans: &[Span]) -> String {
+    let spans: Vec<_> = spans
+        .iter()
+        .map(|span| {
+            format!(
+                r#"{{"name":{},"elapsed_ns":{},"children":{}}}"#,
+                json_string(span.name),
+                span.elapsed.as_nanos(),
+                spans_json(&span.children)
+            )
+        })
+        .collect();
+    format!("[{}]", spans.join(","))
+}
+
+fn json_string(str: &str) -> String {
+    let mut quoted = String::with_capacity(str.len() + 2);
+    quoted.push('"');
+    for c in str.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            c if c.is_control() => quoted.push_str(&format!("\\u{:04x}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// Formats the reports as a Markdown table with a row per part, for pasting
+/// into the README.
+pub fn markdown(reports: &[DayReport]) -> String {
+    use std::fmt::Write;
+
+    let mut output = String::from(
+        "| Day | Part | Answer | Implementation | Parse | Elapsed |\n\
+         |----:|:-----|-------:|:---------------|------:|--------:|\n",
+    );
+    for report in reports {
+        for result in &report.parts {
+            writeln!(
+                output,
+                "| {:02} | {} | {} | {} | {:.2?} | {:.2?} |",
+                report.day,
+                result.part,
+                result.outcome().replace('|', "\\|").replace('\n', "<br>"),
+                result.implementation,
+                report.parse,
+                result.elapsed
+            )
+            .unwrap();
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn report() -> DayReport {
+        let part = |part, answer| PartResult {
+            part,
+            implementation: "default",
+            answer,
+            elapsed: Duration::from_micros(1500),
+            spans: Vec::new(),
+            timed_out: None,
+            panicked: None,
+        };
+        DayReport {
+            day: 7,
+            parse: Duration::from_nanos(250),
+            parse_spans: vec![Span {
+                name: "grid",
+                elapsed: Duration::from_nanos(200),
+                children: Vec::new(),
+            }],
+            parts: [
+                part(Part::One, Answer::Str("a|\"b\"".into())),
+                PartResult {
+                    panicked: Some("index out of bounds".into()),
+                    ..part(Part::Two, Answer::Unsolved)
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_json() {
+        assert_eq!(
+            json(&[report()]),
+            concat!(
+                r#"[{"day":7,"parse_ns":250,"parse_spans":[{"name":"grid","elapsed_ns":200,"children":[]}],"parts":["#,
+                r#"{"part":"One","implementation":"default","answer":"a|\"b\"","elapsed_ns":1500000,"timed_out":null,"panicked":null,"spans":[]},"#,
+                r#"{"part":"Two","implementation":"default","answer":null,"elapsed_ns":1500000,"timed_out":null,"panicked":"index out of bounds","spans":[]}]}]"#
+            )
+        );
+        assert_eq!(json(&[]), "[]");
+        assert_eq!(json_string("a\tb\n"), r#""a\u0009b\n""#);
+    }
+
+    #[test]
+    fn test_markdown() {
+        let mut report = report();
+        report.parts[1].panicked = Some("index out of bounds\nat line 3".into());
+
+        assert_eq!(
+            markdown(&[report]),
+            "| Day | Part | Answer | Implementation | Parse | Elapsed |\n\
+             |----:|:-----|-------:|:---------------|------:|--------:|\n\
+             | 07 | One | a\\|\"b\" | default | 250.00ns | 1.50ms |\n\
+             | 07 | Two | Panicked: index out of bounds<br>at line 3 | default | 250.00ns | 1.50ms |\n"
+        );
+    }
+}
@@ -0,0 +1,167 @@
+use crate::{
+    Implementation, ParseError, Solution,
+    report::{DayReport, Part, PartResult},
+};
+
+/// Parts still running after this long are cut short, if they poll
+/// `budget::token`.
+pub const DEFAULT_BUDGET: std::time::Duration = std::time::Duration::from_secs(60);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Config {
+    /// Wall clock time per part, `None` for no limit.
+    pub budget: Option<std::time::Duration>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            budget: Some(DEFAULT_BUDGET),
+        }
+    }
+}
+
+/// Type erased entry for a single day, so days with different input types can
+/// live in the same list.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub day: u8,
+    pub run: fn(&str, &Config) -> Result<DayReport, ParseError>,
+    pub bench: fn(&str, &crate::bench::Config) -> Result<crate::bench::DayBench, ParseError>,
+    pub compare:
+        fn(&str, &crate::bench::Config) -> Result<crate::compare::DayComparison, ParseError>,
+}
+
+impl Day {
+    pub fn new<S: Solution>() -> Self {
+        Self {
+            day: S::DAY,
+            run: run::<S>,
+            bench: crate::bench::bench::<S>,
+            compare: crate::compare::compare::<S>,
+        }
+    }
+}
+
+/// The message a part panicked with, if it was a string as for `panic!` and
+/// `unwrap`.
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    match payload.downcast_ref::<&str>() {
+        Some(message) => (*message).to_owned(),
+        None => payload
+            .downcast_ref::<String>()
+            .cloned()
+            .unwrap_or_else(|| "Box<dyn Any>".to_owned()),
+    }
+}
+
+fn time_part<I>(implementation: &Implementation<I>, input: &I, config: &Config) -> PartResult {
+    let ((answer, elapsed), spans) = crate::span::record(|| {
+        let now = std::time::Instant::now();
+        let answer = crate::budget::run(config.budget, || {
+            // A bug in one part should not take the other parts and days down
+            // with it.
+            std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                (implementation.solve)(input)
+            }))
+        });
+        (answer, now.elapsed())
+    });
+
+    let (answer, timed_out, panicked) = match answer {
+        Ok(Ok(answer)) => (answer, None, None),
+        Ok(Err(payload)) => (
+            crate::Answer::Unsolved,
+            None,
+            Some(panic_message(&*payload)),
+        ),
+        Err(timed_out) => (crate::Answer::Unsolved, Some(timed_out), None),
+    };
+    PartResult {
+        part: implementation.part,
+        implementation: implementation.name,
+        answer,
+        elapsed,
+        spans,
+        timed_out,
+        panicked,
+    }
+}
+
+/// Parses the input with the error tagged with the day.
+pub fn parse<S: Solution>(input: &str) -> Result<S::Input<'_>, ParseError> {
+    S::parse(input).map_err(|err| err.with_day(S::DAY))
+}
+
+/// Parses the input and runs both parts.
+pub fn run<S: Solution>(input: &str, config: &Config) -> Result<DayReport, ParseError> {
+    let ((input, parse), parse_spans) = crate::span::record(|| {
+        let now = std::time::Instant::now();
+        let input = parse::<S>(input);
+        (input, now.elapsed())
+    });
+    let input = input?;
+
+    Ok(DayReport {
+        day: S::DAY,
+        parse,
+        parse_spans,
+        parts: [Part::One, Part::Two]
+            .map(|part| time_part(&crate::implementations::<S>(part)[0], &input, config)),
+    })
+}
+
+/// Entry point for the per day binaries, reading the puzzle input from stdin.
+/// Accepts `-v`, `-vv` and `-vvv` for logging.
+pub fn main<S: Solution>() {
+    use std::io::Read;
+
+    let verbosity = std::env::args()
+        .skip(1)
+        .filter_map(|arg| crate::log::verbosity_from_flag(&arg))
+        .sum();
+    crate::log::set_verbosity(verbosity);
+
+    let mut input = Vec::new();
+    std::io::stdin().lock().read_to_end(&mut input).unwrap();
+
+    let report = crate::input::load(input)
+        .map_err(|err| err.with_day(S::DAY))
+        .and_then(|input| run::<S>(&input, &Config::default()));
+    match report {
+        Ok(report) => {
+            crate::report::print_day(&report);
+            if report.panicked() {
+                std::process::exit(1);
+            }
+        }
+        Err(err) => {
+            eprintln!("{}", err.diagnostic());
+            std::process::exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_panicking() {
+        let config = Config::default();
+        let panicking =
+            Implementation::new(Part::Two, "default", |input: &Vec<u8>| input[3].into());
+
+        let result = time_part(&panicking, &vec![1, 2], &config);
+        assert_eq!(result.answer, crate::Answer::Unsolved);
+        assert!(result.timed_out.is_none());
+        assert_eq!(
+            result.panicked.as_deref(),
+            Some("index out of bounds: the len is 2 but the index is 3")
+        );
+
+        let result = time_part(&panicking, &vec![1, 2, 3, 4], &config);
+        assert_eq!(result.answer, crate::Answer::Int(4));
+        assert!(result.panicked.is_none());
+    }
+}
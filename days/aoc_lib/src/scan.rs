@@ -0,0 +1,166 @@
+//! Pulling values out of a line with a format string, see `scan!`.
+
+use crate::ParseError;
+
+/// Splits `line` into the text matching each `{}` of `format`, checking the
+/// literal text in between. `line` has to be a slice of `input`.
+///
+/// Each field ends at the first occurrence of the literal after it, so two
+/// `{}` directly next to each other can't be told apart and the first one
+/// takes the rest of the line.
+pub fn fields<'a>(input: &'a str, line: &'a str, format: &str) -> Result<Vec<&'a str>, ParseError> {
+    let mut literals = format.split("{}");
+    let expected = |rest: &'a str, literal: &str| {
+        ParseError::at(
+            input,
+            rest,
+            format!("Expected '{literal}' as in '{format}'"),
+        )
+    };
+
+    // Split always returns at least one item.
+    let prefix = literals.next().unwrap();
+    let mut rest = line
+        .strip_prefix(prefix)
+        .ok_or_else(|| expected(line, prefix))?;
+
+    let mut fields = Vec::new();
+    let mut literals = literals.peekable();
+    while let Some(literal) = literals.next() {
+        let is_last = literals.peek().is_none();
+        if literal.is_empty() {
+            fields.push(rest);
+            rest = &rest[rest.len()..];
+        } else if is_last {
+            // Anchored at the end so the field may contain the suffix itself.
+            let field = rest
+                .strip_suffix(literal)
+                .ok_or_else(|| expected(&rest[rest.len()..], literal))?;
+            fields.push(field);
+            rest = &rest[rest.len()..];
+        } else {
+            let end = rest.find(literal).ok_or_else(|| expected(rest, literal))?;
+            fields.push(&rest[..end]);
+            rest = &rest[end + literal.len()..];
+        }
+    }
+
+    if !rest.is_empty() {
+        return Err(ParseError::at(input, rest, "Unexpected trailing input"));
+    }
+    Ok(fields)
+}
+
+/// Every integer in `text`, which has to be a slice of `input`. A `-` directly
+/// before the digits is a sign unless it follows a letter or digit, so ranges
+/// like `11-22` give two positive numbers.
+pub fn integers<T>(input: &str, text: &str) -> Result<Vec<T>, ParseError>
+where
+    T: std::str::FromStr,
+    T::Err: std::fmt::Display,
+{
+    let bytes = text.as_bytes();
+    let mut numbers = Vec::new();
+    let mut idx = 0;
+
+    while idx < bytes.len() {
+        if !bytes[idx].is_ascii_digit() {
+            idx += 1;
+            continue;
+        }
+
+        let mut start = idx;
+        while idx < bytes.len() && bytes[idx].is_ascii_digit() {
+            idx += 1;
+        }
+        if start > 0
+            && bytes[start - 1] == b'-'
+            && (start < 2 || !bytes[start - 2].is_ascii_alphanumeric())
+        {
+            start -= 1;
+        }
+        numbers.push(crate::parse_span(input, &text[start..idx])?);
+    }
+
+    Ok(numbers)
+}
+
+/// Extracts the `{}` fields of a format string from a line and parses them,
+/// returning `Result<(T1, T2, ..), ParseError>`.
+///
+/// ```
+/// use aoc_lib::scan;
+///
+/// let (x, y, z) = scan!("162,817,-812", "{},{},{}" => i64, i64, i64).unwrap();
+/// assert_eq!((x, y, z), (162, 817, -812));
+/// ```
+///
+/// Errors point into the line. Pass the whole input first to get the right
+/// line number, the line then has to be a slice of it:
+///
+/// ```
+/// use aoc_lib::scan;
+///
+/// let input = "1-3\n5-x";
+/// let line = input.lines().nth(1).unwrap();
+/// let err = scan!(input, line, "{}-{}" => u64, u64).unwrap_err();
+/// assert_eq!((err.line, err.column), (2, 3));
+/// ```
+#[macro_export]
+macro_rules! scan {
+    ($line:expr, $format:literal => $($ty:ty),+ $(,)?) => {{
+        let line: &str = $line;
+        $crate::scan!(line, line, $format => $($ty),+)
+    }};
+    ($input:expr, $line:expr, $format:literal => $($ty:ty),+ $(,)?) => {{
+        let input: &str = $input;
+        $crate::scan::fields(input, $line, $format).and_then(|fields| {
+            let types = [$(stringify!($ty)),+];
+            assert_eq!(
+                fields.len(),
+                types.len(),
+                "scan! format '{}' does not have a {{}} per type",
+                $format
+            );
+            let mut fields = fields.into_iter();
+            Ok(($($crate::parse_span::<$ty>(input, fields.next().unwrap())?,)+))
+        })
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scan() {
+        assert_eq!(scan!("11-22", "{}-{}" => u64, u64), Ok((11, 22)));
+        assert_eq!(scan!("R14", "R{}" => i32), Ok((14,)));
+        assert_eq!(
+            scan!("4x5: 1 2", "{}x{}: {}" => usize, usize, String),
+            Ok((4, 5, "1 2".to_owned()))
+        );
+        assert_eq!(scan!("[1]]", "[{}]" => String), Ok(("1]".to_owned(),)));
+
+        let err = scan!("11,22", "{}-{}" => u64, u64).unwrap_err();
+        assert_eq!(
+            (err.column, err.message.as_str()),
+            (1, "Expected '-' as in '{}-{}'")
+        );
+        assert!(scan!("1,2,3,4", "{},{},{}" => u8, u8, u8).is_err());
+    }
+
+    #[test]
+    fn test_integers() {
+        let tests = [
+            ("11-22,95-115", vec![11, 22, 95, 115]),
+            ("x=-3, y=+4", vec![-3, 4]),
+            ("-1 -2", vec![-1, -2]),
+            ("none", vec![]),
+        ];
+
+        for (text, expected) in tests {
+            assert_eq!(integers::<i64>(text, text), Ok(expected), "{text}");
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use crate::{Answer, ParseError, report::Part};
+
+/// Function solving a single part for an already parsed input.
+pub type PartFn<I> = fn(&I) -> Answer;
+
+/// A named way of solving one of the parts.
+pub struct Implementation<I> {
+    pub part: Part,
+    pub name: &'static str,
+    pub solve: PartFn<I>,
+}
+
+impl<I> Implementation<I> {
+    pub fn new(part: Part, name: &'static str, solve: PartFn<I>) -> Self {
+        Self { part, name, solve }
+    }
+}
+
+/// A single day of the calendar.
+///
+/// The input is read once into a single buffer and parsed once, and both parts
+/// get a shared reference to the parsed value, so any part needing to mutate
+/// the input has to clone it itself. The parsed input may borrow from the
+/// buffer, e.g. `type Input<'a> = Vec<&'a str>` rather than copying lines.
+pub trait Solution {
+    /// Day of the month the puzzle was released on.
+    const DAY: u8;
+
+    type Input<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError>;
+    fn part_one(input: &Self::Input<'_>) -> Answer;
+
+    /// Defaults to unsolved since the last day only has a single part.
+    fn part_two(_input: &Self::Input<'_>) -> Answer {
+        Answer::Unsolved
+    }
+
+    /// Other ways of solving the parts, which must agree with `part_one` and
+    /// `part_two`. Kept around to compare approaches against each other.
+    fn alternatives<'a>() -> Vec<Implementation<Self::Input<'a>>> {
+        Vec::new()
+    }
+}
+
+/// Every implementation of the part, starting with the default one.
+pub fn implementations<'a, S: Solution>(part: Part) -> Vec<Implementation<S::Input<'a>>> {
+    let default = match part {
+        Part::One => S::part_one,
+        Part::Two => S::part_two,
+    };
+
+    std::iter::once(Implementation::new(part, "default", default))
+        .chain(
+            S::alternatives()
+                .into_iter()
+                .filter(|implementation| implementation.part == part),
+        )
+        .collect()
+}
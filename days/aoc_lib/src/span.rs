@@ -0,0 +1,138 @@
+//! Named timing spans, so a solution can show where its time goes.
+//!
+//! ```
+//! use aoc_lib::span;
+//!
+//! let (sum, spans) = span::record(|| {
+//!     let numbers: Vec<u64> = span::time("build", || (1..=100).collect());
+//!     let _search = span::enter("sum");
+//!     numbers.iter().sum::<u64>()
+//! });
+//! assert_eq!(sum, 5050);
+//! assert_eq!(spans.iter().map(|span| span.name).collect::<Vec<_>>(), ["build", "sum"]);
+//! ```
+//!
+//! Spans are only kept inside `record`, which the runner wraps around parsing
+//! and each part. Elsewhere, for example while benchmarking, entering a span
+//! is a single thread local check.
+
+use std::cell::RefCell;
+use std::time::{Duration, Instant};
+
+/// A finished span with the spans entered while it was open.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Span {
+    pub name: &'static str,
+    pub elapsed: Duration,
+    pub children: Vec<Span>,
+}
+
+struct Open {
+    name: &'static str,
+    start: Instant,
+    children: Vec<Span>,
+}
+
+thread_local! {
+    /// Currently open spans, `None` outside of `record`.
+    static OPEN: RefCell<Option<Vec<Open>>> = const { RefCell::new(None) };
+}
+
+/// Closes the span when dropped.
+#[must_use = "the span closes as soon as the guard is dropped"]
+pub struct Guard {
+    recording: bool,
+}
+
+impl Drop for Guard {
+    fn drop(&mut self) {
+        if !self.recording {
+            return;
+        }
+        OPEN.with_borrow_mut(|open| {
+            let Some(open) = open else {
+                return;
+            };
+            // The root frame from `record` is never popped by a guard.
+            if open.len() < 2 {
+                return;
+            }
+            let span = open.pop().unwrap();
+            open.last_mut().unwrap().children.push(Span {
+                name: span.name,
+                elapsed: span.start.elapsed(),
+                children: span.children,
+            });
+        });
+    }
+}
+
+/// Opens a span nested in the currently open one, until the guard is dropped.
+pub fn enter(name: &'static str) -> Guard {
+    let recording = OPEN.with_borrow_mut(|open| match open {
+        Some(open) => {
+            open.push(Open {
+                name,
+                start: Instant::now(),
+                children: Vec::new(),
+            });
+            true
+        }
+        None => false,
+    });
+    Guard { recording }
+}
+
+/// Runs `f` inside a span.
+pub fn time<T>(name: &'static str, f: impl FnOnce() -> T) -> T {
+    let _span = enter(name);
+    f()
+}
+
+/// Runs `f`, returning its value and the top level spans entered while it ran.
+pub fn record<T>(f: impl FnOnce() -> T) -> (T, Vec<Span>) {
+    let root = Open {
+        name: "",
+        start: Instant::now(),
+        children: Vec::new(),
+    };
+    let outer = OPEN.replace(Some(vec![root]));
+    let value = f();
+    let mut open = OPEN.replace(outer).unwrap();
+
+    // Only the root is left unless a guard outlived `f`, which closes nothing.
+    open.truncate(1);
+    (value, open.pop().unwrap().children)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nesting() {
+        let ((), spans) = record(|| {
+            let _outer = enter("outer");
+            time("inner", || {
+                let _innermost = enter("innermost");
+            });
+            time("sibling", || ());
+        });
+
+        assert_eq!(spans.len(), 1);
+        let outer = &spans[0];
+        assert_eq!(outer.name, "outer");
+        let names: Vec<_> = outer.children.iter().map(|span| span.name).collect();
+        assert_eq!(names, ["inner", "sibling"]);
+        assert_eq!(outer.children[0].children[0].name, "innermost");
+        assert!(outer.elapsed >= outer.children[0].elapsed);
+    }
+
+    #[test]
+    fn test_not_recording() {
+        // Entering outside of `record` keeps nothing around.
+        time("ignored", || ());
+        let ((), spans) = record(|| ());
+        assert!(spans.is_empty());
+    }
+}
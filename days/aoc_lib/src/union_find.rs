@@ -0,0 +1,128 @@
+/// Disjoint-set forest over the elements `0..len`, with path compression and
+/// union by size.
+#[derive(Debug, Clone)]
+pub struct UnionFind {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    components: usize,
+}
+
+impl UnionFind {
+    /// Every element starts out in its own component.
+    pub fn new(len: usize) -> Self {
+        Self {
+            parent: (0..len).collect(),
+            size: vec![1; len],
+            components: len,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// Representative of the component containing `element`.
+    pub fn find(&mut self, element: usize) -> usize {
+        let mut root = element;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+
+        // Point everything on the path directly at the root.
+        let mut element = element;
+        while self.parent[element] != root {
+            element = std::mem::replace(&mut self.parent[element], root);
+        }
+
+        root
+    }
+
+    /// Joins the components of `a` and `b`, returning false if they already
+    /// were the same component.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+
+        // Hang the smaller tree below the larger one to keep the trees shallow.
+        if self.size[a] < self.size[b] {
+            std::mem::swap(&mut a, &mut b);
+        }
+        self.parent[b] = a;
+        self.size[a] += self.size[b];
+        self.components -= 1;
+
+        true
+    }
+
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    pub fn component_count(&self) -> usize {
+        self.components
+    }
+
+    /// Size of the component containing `element`.
+    pub fn component_size(&mut self, element: usize) -> usize {
+        let root = self.find(element);
+        self.size[root]
+    }
+
+    /// Sizes of every component, in no particular order.
+    pub fn component_sizes(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.len())
+            .filter(|&element| self.parent[element] == element)
+            .map(|root| self.size[root])
+    }
+
+    /// Members of every component, ordered by their smallest member.
+    pub fn components(&mut self) -> impl Iterator<Item = Vec<usize>> + use<> {
+        let mut members: Vec<Vec<usize>> = vec![Vec::new(); self.len()];
+        for element in 0..self.len() {
+            let root = self.find(element);
+            members[root].push(element);
+        }
+
+        let mut components: Vec<_> = members
+            .into_iter()
+            .filter(|component| !component.is_empty())
+            .collect();
+        components.sort_unstable_by_key(|component| component[0]);
+        components.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_union_find() {
+        let mut union_find = UnionFind::new(6);
+        assert_eq!(union_find.component_count(), 6);
+
+        assert!(union_find.union(0, 1));
+        assert!(union_find.union(2, 3));
+        assert!(union_find.union(1, 3));
+        assert!(!union_find.union(0, 2), "Already connected");
+
+        assert!(union_find.connected(0, 3));
+        assert!(!union_find.connected(0, 4));
+        assert_eq!(union_find.component_count(), 3);
+        assert_eq!(union_find.component_size(2), 4);
+        assert_eq!(union_find.component_size(5), 1);
+
+        let mut sizes: Vec<_> = union_find.component_sizes().collect();
+        sizes.sort_unstable();
+        assert_eq!(sizes, vec![1, 1, 4]);
+
+        let components: Vec<_> = union_find.components().collect();
+        assert_eq!(components, vec![vec![0, 1, 2, 3], vec![4], vec![5]]);
+    }
+}